@@ -1,19 +1,34 @@
 use core::f32;
-use std::f32::consts::PI;
+use std::{
+    cmp::Ordering,
+    f32::consts::{PI, TAU},
+    ops::{Add, Mul, Neg, Sub},
+};
 
 use crate::comparators::are_equal;
 
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Angle {
     value: f32,
     representation: AngleRepresentation,
 }
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum AngleRepresentation {
     Radians,
     Degrees,
 }
 
+impl AngleRepresentation {
+    const fn full_turn(&self) -> f32 {
+        match self {
+            AngleRepresentation::Radians => TAU,
+            AngleRepresentation::Degrees => 360.0,
+        }
+    }
+}
+
 impl Angle {
     pub const fn radians(radians: f32) -> Angle {
         Angle {
@@ -43,6 +58,24 @@ impl Angle {
         }
     }
 
+    pub const fn representation(&self) -> &AngleRepresentation {
+        &self.representation
+    }
+
+    const fn value_in(&self, representation: &AngleRepresentation) -> f32 {
+        match representation {
+            AngleRepresentation::Radians => self.as_radians(),
+            AngleRepresentation::Degrees => self.as_degrees(),
+        }
+    }
+
+    const fn with_value(&self, value: f32) -> Angle {
+        match self.representation {
+            AngleRepresentation::Radians => Angle::radians(value),
+            AngleRepresentation::Degrees => Angle::degrees(value),
+        }
+    }
+
     pub fn sin(&self) -> f32 {
         f32::sin(self.as_radians())
     }
@@ -59,16 +92,104 @@ impl Angle {
         Angle::radians(y.atan2(x))
     }
 
+    /// Wraps angle into [0, 360) degrees, keeping its representation
     pub fn normalized(&self) -> Angle {
-        let mut degrees = self.as_degrees() % 360.0;
-        if degrees < 0.0 {
-            degrees += 360.0;
+        let full_turn = self.representation.full_turn();
+        let mut value = self.value % full_turn;
+        if value < 0.0 {
+            value += full_turn;
         }
-        Angle::degrees(degrees)
+        self.with_value(value)
     }
+
+    /// Wraps angle into (-180, 180] degrees, keeping its representation
+    pub fn normalized_signed(&self) -> Angle {
+        let full_turn = self.representation.full_turn();
+        let mut value = self.normalized().value;
+        if value > full_turn / 2.0 {
+            value -= full_turn;
+        }
+        self.with_value(value)
+    }
+
     pub fn is_inline_with(&self, angle: &Angle) -> bool {
         let normed1 = self.normalized();
         let normed2 = angle.normalized();
         are_equal(normed1.as_degrees() % 180.0, normed2.as_degrees() % 180.0)
     }
+
+    /// Signed rotation in (-180, 180] degrees that turns `self` into `to` along the shorter arc
+    pub fn shortest_difference(&self, to: &Angle) -> Angle {
+        (to.clone() - self.clone()).normalized_signed()
+    }
+
+    /// Linear interpolation of raw angle values, `t = 0` gives `self` and `t = 1` gives `to`
+    pub fn lerp(&self, to: &Angle, t: f32) -> Angle {
+        self.clone() + (to.clone() - self.clone()) * t
+    }
+
+    /// Interpolation along the shorter arc between `self` and `to`
+    pub fn slerp(&self, to: &Angle, t: f32) -> Angle {
+        self.clone() + self.shortest_difference(to) * t
+    }
+}
+
+// arithmetics, the result keeps representation of the left operand
+impl Add for Angle {
+    type Output = Angle;
+
+    fn add(self, other: Angle) -> Angle {
+        let value = self.value + other.value_in(&self.representation);
+        self.with_value(value)
+    }
+}
+
+impl Sub for Angle {
+    type Output = Angle;
+
+    fn sub(self, other: Angle) -> Angle {
+        let value = self.value - other.value_in(&self.representation);
+        self.with_value(value)
+    }
+}
+
+impl Mul<f32> for Angle {
+    type Output = Angle;
+
+    fn mul(self, s: f32) -> Angle {
+        self.with_value(self.value * s)
+    }
+}
+
+impl Mul<Angle> for f32 {
+    type Output = Angle;
+
+    fn mul(self, angle: Angle) -> Angle {
+        angle * self
+    }
+}
+
+impl Neg for Angle {
+    type Output = Angle;
+
+    fn neg(self) -> Angle {
+        self.with_value(-self.value)
+    }
+}
+
+/// Angles are compared by their raw value, so 0 and 360 degrees are different angles.
+/// Use [`Angle::normalized`] before comparing directions.
+impl PartialEq for Angle {
+    fn eq(&self, other: &Angle) -> bool {
+        are_equal(self.as_radians(), other.as_radians())
+    }
+}
+
+impl PartialOrd for Angle {
+    fn partial_cmp(&self, other: &Angle) -> Option<Ordering> {
+        if self == other {
+            return Some(Ordering::Equal);
+        }
+        self.as_radians().partial_cmp(&other.as_radians())
+    }
 }
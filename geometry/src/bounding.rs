@@ -0,0 +1,317 @@
+use std::f32::consts::PI;
+
+use crate::{
+    angle::Angle,
+    comparators::{is_zero, less_or_equal},
+    point::{Point2D, dist, dist2},
+    shapes::{
+        Shape2D, Shape2DType,
+        affine::{Affine2D, EmbedInAffine2D, TypedAffine2D},
+        circle::Circle,
+        polygon::Polygon,
+        rectangle::Rectangle,
+    },
+    vector::Vector2D,
+};
+
+/// Axis aligned bounding rectangle, `None` for unbounded shapes
+pub trait BoundingBox {
+    fn bounding_box(&self) -> Option<TypedAffine2D<Rectangle>>;
+}
+
+impl BoundingBox for Affine2D {
+    fn bounding_box(&self) -> Option<TypedAffine2D<Rectangle>> {
+        match &self.shape {
+            Shape2D::Rectangle { rect } => Some(rect.embed_affine(&self.point)),
+            Shape2D::RoundedRectangle { rounded_rect } => Some(
+                Rectangle {
+                    width: rounded_rect.width,
+                    height: rounded_rect.height,
+                }
+                .embed_affine(&self.point),
+            ),
+            Shape2D::Circle { circle } => Some(
+                Rectangle {
+                    width: 2.0 * circle.radius,
+                    height: 2.0 * circle.radius,
+                }
+                .embed_affine(&self.point),
+            ),
+            Shape2D::Line { .. } => None,
+            _ => bounding_box_of_points(&get_hull_points(self, 0)?),
+        }
+    }
+}
+
+impl<T> BoundingBox for TypedAffine2D<T>
+where
+    T: Shape2DType,
+{
+    fn bounding_box(&self) -> Option<TypedAffine2D<Rectangle>> {
+        let aff: Affine2D = self.clone().into();
+        aff.bounding_box()
+    }
+}
+
+pub fn bounding_box_of_points(points: &[Point2D]) -> Option<TypedAffine2D<Rectangle>> {
+    let first = points.first()?;
+    let (mut min, mut max) = (*first, *first);
+    for p in points {
+        min = Point2D {
+            x: min.x.min(p.x),
+            y: min.y.min(p.y),
+        };
+        max = Point2D {
+            x: max.x.max(p.x),
+            y: max.y.max(p.y),
+        };
+    }
+    let rect = Rectangle {
+        width: max.x - min.x,
+        height: max.y - min.y,
+    };
+    Some(rect.embed_affine(&(min + (max - min) / 2.0)))
+}
+
+/// Smallest axis aligned rectangle containing all shapes, `None` if any shape is unbounded
+pub fn bounding_box_of_shapes(shapes: &[Affine2D]) -> Option<TypedAffine2D<Rectangle>> {
+    let corners = shapes
+        .iter()
+        .map(|s| s.bounding_box().map(|b| b.get_vertices()))
+        .collect::<Option<Vec<_>>>()?;
+    bounding_box_of_points(&corners.concat())
+}
+
+/// Rectangle rotated by `angle` around its center
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct OrientedRectangle {
+    pub center: Point2D,
+    pub rect: Rectangle,
+    pub angle: Angle,
+}
+
+impl OrientedRectangle {
+    /// Corners in counter-clockwise order
+    pub fn get_vertices(&self) -> [Point2D; 4] {
+        let u = Vector2D::by_angle(&self.angle) * (self.rect.width / 2.0);
+        let v = Vector2D::by_angle(&(self.angle.clone() + Angle::degrees(90.0)))
+            * (self.rect.height / 2.0);
+        [
+            self.center - u.clone() - v.clone(),
+            self.center + u.clone() - v.clone(),
+            self.center + u.clone() + v.clone(),
+            self.center - u + v,
+        ]
+    }
+
+    pub fn to_polygon(&self) -> TypedAffine2D<Polygon> {
+        let vertices = self
+            .get_vertices()
+            .iter()
+            .map(|v| Point2D::zero() + (*v - self.center))
+            .collect();
+        Polygon::new(vertices).embed_affine(&self.center)
+    }
+}
+
+fn cross(o: &Point2D, a: &Point2D, b: &Point2D) -> f32 {
+    (*a - *o).cross(&(*b - *o))
+}
+
+/// Convex hull in counter-clockwise order without collinear points (Andrew's monotone chain)
+pub fn convex_hull(points: &[Point2D]) -> Vec<Point2D> {
+    let mut sorted = points.to_vec();
+    sorted.sort_by(|a, b| a.x.total_cmp(&b.x).then(a.y.total_cmp(&b.y)));
+    sorted.dedup_by(|a, b| a == b);
+    if sorted.len() < 3 {
+        return sorted;
+    }
+    let mut hull: Vec<Point2D> = Vec::with_capacity(2 * sorted.len());
+    for pass in [sorted.clone(), sorted.into_iter().rev().collect()] {
+        let base = hull.len();
+        for p in pass {
+            while hull.len() >= base + 2
+                && less_or_equal(cross(&hull[hull.len() - 2], &hull[hull.len() - 1], &p), 0.0)
+            {
+                hull.pop();
+            }
+            hull.push(p);
+        }
+        // last point of each chain is the first point of the other one
+        hull.pop();
+    }
+    hull
+}
+
+/// Points whose convex hull contains the shape, circles and rounded corners are replaced
+/// by circumscribed regular polygons with `circle_segments` sides (at least 3)
+pub fn get_hull_points(shape: &Affine2D, circle_segments: usize) -> Option<Vec<Point2D>> {
+    match &shape.shape {
+        Shape2D::Point => Some(vec![shape.point]),
+        Shape2D::Line { .. } => None,
+        Shape2D::Segment { segment } => {
+            Some(segment.embed_affine(&shape.point).get_end_points().to_vec())
+        }
+        Shape2D::Rectangle { rect } => {
+            Some(rect.embed_affine(&shape.point).get_vertices().to_vec())
+        }
+        Shape2D::Polygon { polygon } => Some(polygon.embed_affine(&shape.point).get_vertices()),
+        Shape2D::RoundedRectangle { rounded_rect } => Some(
+            rounded_rect
+                .embed_affine(&shape.point)
+                .get_corner_circles()
+                .iter()
+                .flat_map(|c| circumscribed_points(c, circle_segments))
+                .collect(),
+        ),
+        Shape2D::Circle { circle } => Some(circumscribed_points(
+            &circle.embed_affine(&shape.point),
+            circle_segments,
+        )),
+    }
+}
+
+fn circumscribed_points(c: &TypedAffine2D<Circle>, n: usize) -> Vec<Point2D> {
+    let n = n.max(3);
+    let radius = c.shape.radius / f32::cos(PI / n as f32);
+    (0..n)
+        .map(|i| {
+            let angle = Angle::radians(2.0 * PI * i as f32 / n as f32);
+            c.point + Vector2D::by_angle(&angle) * radius
+        })
+        .collect()
+}
+
+/// Convex hull of all shapes, `None` if any shape is unbounded
+pub fn convex_hull_of_shapes(shapes: &[Affine2D], circle_segments: usize) -> Option<Vec<Point2D>> {
+    let points = shapes
+        .iter()
+        .map(|s| get_hull_points(s, circle_segments))
+        .collect::<Option<Vec<_>>>()?;
+    Some(convex_hull(&points.concat()))
+}
+
+/// Minimum area rectangle of any orientation containing all points, one of its sides
+/// is always collinear with a convex hull edge
+pub fn min_area_rect(points: &[Point2D]) -> Option<OrientedRectangle> {
+    let hull = convex_hull(points);
+    let first = *hull.first()?;
+    let mut best = OrientedRectangle {
+        center: first,
+        rect: Rectangle {
+            width: 0.0,
+            height: 0.0,
+        },
+        angle: Angle::degrees(0.0),
+    };
+    let mut best_area = f32::INFINITY;
+    if hull.len() == 2 {
+        let v = hull[1] - hull[0];
+        best.center = hull[0] + v.clone() / 2.0;
+        best.rect.width = v.length();
+        best.angle = v.get_angle();
+        return Some(best);
+    }
+    for (a, b) in hull.iter().zip(hull.iter().cycle().skip(1)) {
+        let Some(u) = (*b - *a).normalized() else {
+            continue;
+        };
+        let v = Vector2D { x: -u.y, y: u.x };
+        let (mut min_u, mut max_u, mut min_v, mut max_v) = (
+            f32::INFINITY,
+            f32::NEG_INFINITY,
+            f32::INFINITY,
+            f32::NEG_INFINITY,
+        );
+        for p in &hull {
+            let d = *p - *a;
+            let (pu, pv) = (d.dot(&u), d.dot(&v));
+            (min_u, max_u) = (min_u.min(pu), max_u.max(pu));
+            (min_v, max_v) = (min_v.min(pv), max_v.max(pv));
+        }
+        let area = (max_u - min_u) * (max_v - min_v);
+        if area < best_area {
+            best_area = area;
+            best = OrientedRectangle {
+                center: *a
+                    + u.clone() * ((min_u + max_u) / 2.0)
+                    + v.clone() * ((min_v + max_v) / 2.0),
+                rect: Rectangle {
+                    width: max_u - min_u,
+                    height: max_v - min_v,
+                },
+                angle: u.get_angle(),
+            };
+        }
+    }
+    Some(best)
+}
+
+fn circle_from_two(a: &Point2D, b: &Point2D) -> TypedAffine2D<Circle> {
+    Circle {
+        radius: dist(a, b) / 2.0,
+    }
+    .embed_affine(&(*a + (*b - *a) / 2.0))
+}
+
+fn circle_from_three(a: &Point2D, b: &Point2D, c: &Point2D) -> TypedAffine2D<Circle> {
+    let (ab, ac) = (*b - *a, *c - *a);
+    let d = 2.0 * ab.cross(&ac);
+    if is_zero(d.abs()) {
+        // collinear points, the circle spans the two farthest ones
+        return [(a, b), (a, c), (b, c)]
+            .into_iter()
+            .map(|(p, q)| circle_from_two(p, q))
+            .max_by(|c1, c2| c1.shape.radius.total_cmp(&c2.shape.radius))
+            .expect("three candidate circles");
+    }
+    let (ab2, ac2) = (ab.dot(&ab), ac.dot(&ac));
+    let center = *a
+        + Vector2D {
+            x: (ac.y * ab2 - ab.y * ac2) / d,
+            y: (ab.x * ac2 - ac.x * ab2) / d,
+        };
+    Circle {
+        radius: dist(&center, a),
+    }
+    .embed_affine(&center)
+}
+
+fn is_in_circle(c: &TypedAffine2D<Circle>, p: &Point2D) -> bool {
+    less_or_equal(dist2(&c.point, p), c.shape.radius * c.shape.radius)
+}
+
+/// Smallest circle containing all points (incremental Welzl algorithm on the convex hull,
+/// expected linear time)
+pub fn min_enclosing_circle(points: &[Point2D]) -> Option<TypedAffine2D<Circle>> {
+    let mut hull = convex_hull(points);
+    // the expected time needs the points in random order, a fixed seed keeps results
+    // reproducible (Fisher-Yates shuffle driven by xorshift)
+    let mut state: u64 = 0x2545_F491_4F6C_DD1D;
+    for i in (1..hull.len()).rev() {
+        state ^= state << 13;
+        state ^= state >> 7;
+        state ^= state << 17;
+        hull.swap(i, (state % (i as u64 + 1)) as usize);
+    }
+    let mut circle = Circle { radius: 0.0 }.embed_affine(hull.first()?);
+    for i in 1..hull.len() {
+        if is_in_circle(&circle, &hull[i]) {
+            continue;
+        }
+        circle = Circle { radius: 0.0 }.embed_affine(&hull[i]);
+        for j in 0..i {
+            if is_in_circle(&circle, &hull[j]) {
+                continue;
+            }
+            circle = circle_from_two(&hull[i], &hull[j]);
+            for k in 0..j {
+                if !is_in_circle(&circle, &hull[k]) {
+                    circle = circle_from_three(&hull[i], &hull[j], &hull[k]);
+                }
+            }
+        }
+    }
+    Some(circle)
+}
@@ -0,0 +1,381 @@
+use crate::{
+    comparators::{is_zero, less_or_equal},
+    point::Point2D,
+    shapes::polygon::{Polygon, PolygonWithHoles},
+    vector::Vector2D,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BooleanOperation {
+    Union,
+    Intersection,
+    Difference,
+    Xor,
+}
+
+/// Position of a piece of one operand's border relative to the other operand
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum EdgePosition {
+    Inside,
+    Outside,
+    /// Border of both operands, with interiors on the same side
+    SharedSame,
+    /// Border of both operands, with interiors on opposite sides
+    SharedOpposite,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Edge {
+    start: Point2D,
+    end: Point2D,
+}
+
+impl Edge {
+    fn vector(&self) -> Vector2D {
+        self.end - self.start
+    }
+
+    fn reversed(&self) -> Edge {
+        Edge {
+            start: self.end,
+            end: self.start,
+        }
+    }
+
+    fn middle(&self) -> Point2D {
+        self.start + self.vector() / 2.0
+    }
+}
+
+pub fn union(a: &[PolygonWithHoles], b: &[PolygonWithHoles]) -> Vec<PolygonWithHoles> {
+    boolean_operation(a, b, BooleanOperation::Union)
+}
+
+pub fn intersection(a: &[PolygonWithHoles], b: &[PolygonWithHoles]) -> Vec<PolygonWithHoles> {
+    boolean_operation(a, b, BooleanOperation::Intersection)
+}
+
+pub fn difference(a: &[PolygonWithHoles], b: &[PolygonWithHoles]) -> Vec<PolygonWithHoles> {
+    boolean_operation(a, b, BooleanOperation::Difference)
+}
+
+pub fn xor(a: &[PolygonWithHoles], b: &[PolygonWithHoles]) -> Vec<PolygonWithHoles> {
+    boolean_operation(a, b, BooleanOperation::Xor)
+}
+
+/// Computes boolean operation of two regions, each given as a set of disjoint polygons.
+/// Contours are expected to be simple, i.e. without self intersections.
+pub fn boolean_operation(
+    a: &[PolygonWithHoles],
+    b: &[PolygonWithHoles],
+    operation: BooleanOperation,
+) -> Vec<PolygonWithHoles> {
+    let (edges_a, edges_b) = split_edges(&get_edges(a), &get_edges(b));
+    let positions_a = classify(&edges_a, &edges_b, b);
+    let positions_b = classify(&edges_b, &edges_a, a);
+
+    let mut selected = Vec::new();
+    for (edge, position) in edges_a.iter().zip(positions_a) {
+        let keep = match (operation, position) {
+            (BooleanOperation::Union, EdgePosition::Outside | EdgePosition::SharedSame) => {
+                Some(*edge)
+            }
+            (BooleanOperation::Intersection, EdgePosition::Inside | EdgePosition::SharedSame) => {
+                Some(*edge)
+            }
+            (
+                BooleanOperation::Difference,
+                EdgePosition::Outside | EdgePosition::SharedOpposite,
+            ) => Some(*edge),
+            (BooleanOperation::Xor, EdgePosition::Outside) => Some(*edge),
+            (BooleanOperation::Xor, EdgePosition::Inside) => Some(edge.reversed()),
+            _ => None,
+        };
+        selected.extend(keep);
+    }
+    for (edge, position) in edges_b.iter().zip(positions_b) {
+        // shared pieces were already taken from the first operand
+        let keep = match (operation, position) {
+            (BooleanOperation::Union, EdgePosition::Outside) => Some(*edge),
+            (BooleanOperation::Intersection, EdgePosition::Inside) => Some(*edge),
+            (BooleanOperation::Difference, EdgePosition::Inside) => Some(edge.reversed()),
+            (BooleanOperation::Xor, EdgePosition::Outside) => Some(*edge),
+            (BooleanOperation::Xor, EdgePosition::Inside) => Some(edge.reversed()),
+            _ => None,
+        };
+        selected.extend(keep);
+    }
+    assemble(link_contours(selected))
+}
+
+/// Merges contours which may intersect themselves and each other into simple polygons
+/// covering every point with positive winding number
+pub fn positive_fill(contours: &[Polygon]) -> Vec<PolygonWithHoles> {
+    let edges: Vec<Edge> = contours
+        .iter()
+        .flat_map(|c| c.get_edges())
+        .filter(|(start, end)| start != end)
+        .map(|(start, end)| Edge { start, end })
+        .collect();
+    let mut selected: Vec<Edge> = Vec::new();
+    for piece in split_self(&edges) {
+        let middle = piece.middle();
+        let Some(direction) = piece.vector().normalized() else {
+            continue;
+        };
+        let side = Vector2D {
+            x: -direction.y,
+            y: direction.x,
+        } * WINDING_PROBE_DISTANCE;
+        let left = winding_number(&edges, &(middle + side.clone()));
+        let right = winding_number(&edges, &(middle - side));
+        let is_border = left > 0 && right <= 0;
+        let is_duplicate = selected
+            .iter()
+            .any(|e| e.start == piece.start && e.end == piece.end);
+        if is_border && !is_duplicate {
+            selected.push(piece);
+        }
+    }
+    assemble(link_contours(selected))
+}
+
+/// How far from the border winding numbers are probed when resolving self intersections
+const WINDING_PROBE_DISTANCE: f32 = 0.001;
+
+fn winding_number(edges: &[Edge], point: &Point2D) -> i32 {
+    edges
+        .iter()
+        .map(|e| {
+            let side = e.vector().cross(&(*point - e.start));
+            if e.start.y <= point.y && e.end.y > point.y && side > 0.0 {
+                1
+            } else if e.start.y > point.y && e.end.y <= point.y && side < 0.0 {
+                -1
+            } else {
+                0
+            }
+        })
+        .sum()
+}
+
+fn get_edges(region: &[PolygonWithHoles]) -> Vec<Edge> {
+    region
+        .iter()
+        .flat_map(|p| p.contours())
+        .flat_map(|c| c.get_edges())
+        .filter(|(start, end)| start != end)
+        .map(|(start, end)| Edge { start, end })
+        .collect()
+}
+
+/// Splits edges of both sets at every point where they meet an edge of the other set,
+/// so that any two pieces either overlap completely or share at most their end points
+fn split_edges(a: &[Edge], b: &[Edge]) -> (Vec<Edge>, Vec<Edge>) {
+    let mut cuts_a: Vec<Vec<(f32, Point2D)>> = vec![vec![]; a.len()];
+    let mut cuts_b: Vec<Vec<(f32, Point2D)>> = vec![vec![]; b.len()];
+    for (i, ea) in a.iter().enumerate() {
+        for (j, eb) in b.iter().enumerate() {
+            add_cuts(ea, eb, &mut cuts_a[i], &mut cuts_b[j]);
+        }
+    }
+    (apply_cuts(a, cuts_a), apply_cuts(b, cuts_b))
+}
+
+fn split_self(edges: &[Edge]) -> Vec<Edge> {
+    let mut cuts: Vec<Vec<(f32, Point2D)>> = vec![vec![]; edges.len()];
+    for i in 0..edges.len() {
+        for j in i + 1..edges.len() {
+            let (head, tail) = cuts.split_at_mut(j);
+            add_cuts(&edges[i], &edges[j], &mut head[i], &mut tail[0]);
+        }
+    }
+    apply_cuts(edges, cuts)
+}
+
+fn project(edge: &Edge, point: &Point2D) -> f32 {
+    let v = edge.vector();
+    v.dot(&(*point - edge.start)) / v.dot(&v)
+}
+
+fn is_in_unit_range(t: f32) -> bool {
+    less_or_equal(0.0, t) && less_or_equal(t, 1.0)
+}
+
+fn add_cuts(
+    a: &Edge,
+    b: &Edge,
+    cuts_a: &mut Vec<(f32, Point2D)>,
+    cuts_b: &mut Vec<(f32, Point2D)>,
+) {
+    let (r, s) = (a.vector(), b.vector());
+    let start_diff = b.start - a.start;
+    let denom = r.cross(&s);
+    if is_zero(denom.abs() / (r.length() * s.length())) {
+        if !is_zero(r.cross(&start_diff).abs() / r.length()) {
+            return;
+        }
+        for point in [b.start, b.end] {
+            let t = project(a, &point);
+            if is_in_unit_range(t) {
+                cuts_a.push((t, point));
+            }
+        }
+        for point in [a.start, a.end] {
+            let t = project(b, &point);
+            if is_in_unit_range(t) {
+                cuts_b.push((t, point));
+            }
+        }
+        return;
+    }
+    let t = start_diff.cross(&s) / denom;
+    let u = start_diff.cross(&r) / denom;
+    if is_in_unit_range(t) && is_in_unit_range(u) {
+        // snap to existing vertices, so both operands share exactly the same points
+        let point = [a.start, a.end, b.start, b.end]
+            .into_iter()
+            .find(|v| *v == a.start + r.clone() * t)
+            .unwrap_or(a.start + r * t);
+        cuts_a.push((t, point));
+        cuts_b.push((u, point));
+    }
+}
+
+fn apply_cuts(edges: &[Edge], cuts: Vec<Vec<(f32, Point2D)>>) -> Vec<Edge> {
+    let mut pieces = Vec::new();
+    for (edge, mut edge_cuts) in edges.iter().zip(cuts) {
+        edge_cuts.sort_by(|c1, c2| c1.0.total_cmp(&c2.0));
+        let mut start = edge.start;
+        for (_, point) in edge_cuts {
+            if point != start && point != edge.end {
+                pieces.push(Edge { start, end: point });
+                start = point;
+            }
+        }
+        pieces.push(Edge {
+            start,
+            end: edge.end,
+        });
+    }
+    pieces
+}
+
+fn classify(edges: &[Edge], other_edges: &[Edge], other: &[PolygonWithHoles]) -> Vec<EdgePosition> {
+    edges
+        .iter()
+        .map(|edge| {
+            for o in other_edges {
+                if o.start == edge.start && o.end == edge.end {
+                    return EdgePosition::SharedSame;
+                }
+                if o.start == edge.end && o.end == edge.start {
+                    return EdgePosition::SharedOpposite;
+                }
+            }
+            let middle = edge.middle();
+            if other.iter().any(|p| p.winds_around(&middle)) {
+                EdgePosition::Inside
+            } else {
+                EdgePosition::Outside
+            }
+        })
+        .collect()
+}
+
+/// Joins directed edges into closed contours, at vertices where several contours meet
+/// the sharpest left turn is taken so that contours stay simple
+fn link_contours(edges: Vec<Edge>) -> Vec<Polygon> {
+    let mut used = vec![false; edges.len()];
+    let mut contours = Vec::new();
+    for first in 0..edges.len() {
+        if used[first] {
+            continue;
+        }
+        used[first] = true;
+        let mut vertices = vec![edges[first].start];
+        let mut current = edges[first];
+        while current.end != edges[first].start {
+            vertices.push(current.end);
+            let incoming = current.vector();
+            let next = (0..edges.len())
+                .filter(|&i| !used[i] && edges[i].start == current.end)
+                .max_by(|&i, &j| {
+                    turn(&incoming, &edges[i], &current)
+                        .total_cmp(&turn(&incoming, &edges[j], &current))
+                });
+            let Some(next) = next else {
+                break;
+            };
+            used[next] = true;
+            current = edges[next];
+        }
+        let contour = remove_collinear(vertices);
+        if contour.len() >= 3 {
+            contours.push(Polygon::new(contour));
+        }
+    }
+    contours
+}
+
+fn turn(incoming: &Vector2D, edge: &Edge, current: &Edge) -> f32 {
+    if edge.end == current.start {
+        return f32::NEG_INFINITY;
+    }
+    let outgoing = edge.vector();
+    f32::atan2(incoming.cross(&outgoing), incoming.dot(&outgoing))
+}
+
+fn remove_collinear(mut vertices: Vec<Point2D>) -> Vec<Point2D> {
+    let mut changed = true;
+    while changed && vertices.len() >= 3 {
+        changed = false;
+        let n = vertices.len();
+        for i in 0..n {
+            let (prev, curr, next) = (
+                vertices[(i + n - 1) % n],
+                vertices[i],
+                vertices[(i + 1) % n],
+            );
+            let (v1, v2) = (curr - prev, next - curr);
+            let degenerate = curr == prev
+                || (is_zero(v1.cross(&v2).abs() / (v1.length() * v2.length()))
+                    && v1.dot(&v2) > 0.0);
+            if degenerate {
+                vertices.remove(i);
+                changed = true;
+                break;
+            }
+        }
+    }
+    vertices
+}
+
+/// Groups contours into polygons, clockwise contours become holes of the smallest
+/// counter-clockwise contour around them
+fn assemble(contours: Vec<Polygon>) -> Vec<PolygonWithHoles> {
+    let (outers, holes): (Vec<Polygon>, Vec<Polygon>) = contours
+        .into_iter()
+        .filter(|c| !is_zero(c.signed_double_area().abs()))
+        .partition(|c| c.is_counter_clockwise());
+    let mut result: Vec<PolygonWithHoles> = outers
+        .into_iter()
+        .map(|outer| PolygonWithHoles {
+            outer,
+            holes: vec![],
+        })
+        .collect();
+    for hole in holes {
+        let probe = hole.get_edges().next().map(|(a, b)| a + (b - a) / 2.0);
+        let owner = (0..result.len())
+            .filter(|&i| probe.is_some_and(|p| result[i].outer.winds_around(&p)))
+            .min_by(|&i, &j| {
+                let area = |k: usize| result[k].outer.signed_double_area();
+                area(i).total_cmp(&area(j))
+            });
+        if let Some(owner) = owner {
+            result[owner].holes.push(hole);
+        }
+    }
+    result
+}
@@ -0,0 +1,242 @@
+use super::{Collisions, CollisionsError};
+use crate::{
+    comparators::EPS,
+    point::Point2D,
+    shapes::{
+        affine::{Affine2D, TypedAffine2D},
+        circle::Circle,
+        rectangle::Rectangle,
+    },
+};
+
+// Both queries stop at the first pair `check_collision` cannot decide and return its
+// error, the pairwise checks borrow the shapes and never clone polygon vertices
+
+/// Indices of the shapes colliding with `point`
+pub fn query_point_many(
+    shapes: &[Affine2D],
+    point: &Point2D,
+) -> Result<Vec<usize>, CollisionsError> {
+    let mut hits = vec![];
+    for (i, shape) in shapes.iter().enumerate() {
+        if shape.check_collision(point)? {
+            hits.push(i);
+        }
+    }
+    Ok(hits)
+}
+
+/// Indices of the shapes colliding with `shape`
+pub fn check_collision_many(
+    shapes: &[Affine2D],
+    shape: &Affine2D,
+) -> Result<Vec<usize>, CollisionsError> {
+    let mut hits = vec![];
+    for (i, other) in shapes.iter().enumerate() {
+        if other.check_collision(shape)? {
+            hits.push(i);
+        }
+    }
+    Ok(hits)
+}
+
+fn indices(mask: &[bool]) -> Vec<usize> {
+    mask.iter()
+        .enumerate()
+        .filter(|(_, hit)| **hit)
+        .map(|(i, _)| i)
+        .collect()
+}
+
+/// Same test as `less_or_equal`, written without branches
+#[inline]
+fn at_most(a: f32, b: f32) -> bool {
+    a - b < EPS
+}
+
+/// Circles kept as one array per field, queries run over all of them in tight loops
+#[derive(Debug, Clone, Default)]
+pub struct CircleSet {
+    xs: Vec<f32>,
+    ys: Vec<f32>,
+    radii: Vec<f32>,
+}
+
+impl CircleSet {
+    pub fn new() -> CircleSet {
+        CircleSet::default()
+    }
+
+    pub fn push(&mut self, circle: &TypedAffine2D<Circle>) -> usize {
+        self.xs.push(circle.point.x);
+        self.ys.push(circle.point.y);
+        self.radii.push(circle.shape.radius);
+        self.len() - 1
+    }
+
+    pub fn len(&self) -> usize {
+        self.xs.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.xs.is_empty()
+    }
+
+    pub fn get(&self, index: usize) -> Option<TypedAffine2D<Circle>> {
+        Some(TypedAffine2D::new(
+            Point2D {
+                x: *self.xs.get(index)?,
+                y: self.ys[index],
+            },
+            Circle {
+                radius: self.radii[index],
+            },
+        ))
+    }
+
+    /// Hit flags of all circles for a point grown by `radius`
+    fn mask_within(&self, point: &Point2D, radius: f32) -> Vec<bool> {
+        self.xs
+            .iter()
+            .zip(&self.ys)
+            .zip(&self.radii)
+            .map(|((x, y), r)| {
+                let (dx, dy) = (x - point.x, y - point.y);
+                at_most(dx * dx + dy * dy, (r + radius) * (r + radius))
+            })
+            .collect()
+    }
+
+    pub fn query_point(&self, point: &Point2D) -> Vec<usize> {
+        indices(&self.mask_within(point, 0.0))
+    }
+
+    pub fn query_circle(&self, circle: &TypedAffine2D<Circle>) -> Vec<usize> {
+        indices(&self.mask_within(&circle.point, circle.shape.radius))
+    }
+
+    pub fn query_rect(&self, rect: &TypedAffine2D<Rectangle>) -> Vec<usize> {
+        let (left, right) = (rect.get_left(), rect.get_right());
+        let (bottom, top) = (rect.get_bottom(), rect.get_top());
+        let mask: Vec<bool> = self
+            .xs
+            .iter()
+            .zip(&self.ys)
+            .zip(&self.radii)
+            .map(|((x, y), r)| {
+                let dx = x - x.clamp(left, right);
+                let dy = y - y.clamp(bottom, top);
+                at_most(dx * dx + dy * dy, r * r)
+            })
+            .collect();
+        indices(&mask)
+    }
+}
+
+impl<'a> FromIterator<&'a TypedAffine2D<Circle>> for CircleSet {
+    fn from_iter<I: IntoIterator<Item = &'a TypedAffine2D<Circle>>>(circles: I) -> Self {
+        let mut set = CircleSet::new();
+        for circle in circles {
+            set.push(circle);
+        }
+        set
+    }
+}
+
+/// Rectangles kept as one array per side, queries run over all of them in tight loops
+#[derive(Debug, Clone, Default)]
+pub struct RectangleSet {
+    lefts: Vec<f32>,
+    rights: Vec<f32>,
+    bottoms: Vec<f32>,
+    tops: Vec<f32>,
+}
+
+impl RectangleSet {
+    pub fn new() -> RectangleSet {
+        RectangleSet::default()
+    }
+
+    pub fn push(&mut self, rect: &TypedAffine2D<Rectangle>) -> usize {
+        self.lefts.push(rect.get_left());
+        self.rights.push(rect.get_right());
+        self.bottoms.push(rect.get_bottom());
+        self.tops.push(rect.get_top());
+        self.len() - 1
+    }
+
+    pub fn len(&self) -> usize {
+        self.lefts.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.lefts.is_empty()
+    }
+
+    pub fn get(&self, index: usize) -> Option<TypedAffine2D<Rectangle>> {
+        let (left, right) = (*self.lefts.get(index)?, self.rights[index]);
+        let (bottom, top) = (self.bottoms[index], self.tops[index]);
+        Some(TypedAffine2D::new(
+            Point2D {
+                x: (left + right) / 2.0,
+                y: (bottom + top) / 2.0,
+            },
+            Rectangle {
+                width: right - left,
+                height: top - bottom,
+            },
+        ))
+    }
+
+    /// Hit flags of all rectangles overlapping the box, edges included
+    fn mask_overlapping(&self, left: f32, right: f32, bottom: f32, top: f32) -> Vec<bool> {
+        self.lefts
+            .iter()
+            .zip(&self.rights)
+            .zip(self.bottoms.iter().zip(&self.tops))
+            .map(|((l, r), (b, t))| {
+                at_most(*l, right) & at_most(left, *r) & at_most(*b, top) & at_most(bottom, *t)
+            })
+            .collect()
+    }
+
+    pub fn query_point(&self, point: &Point2D) -> Vec<usize> {
+        indices(&self.mask_overlapping(point.x, point.x, point.y, point.y))
+    }
+
+    pub fn query_rect(&self, rect: &TypedAffine2D<Rectangle>) -> Vec<usize> {
+        indices(&self.mask_overlapping(
+            rect.get_left(),
+            rect.get_right(),
+            rect.get_bottom(),
+            rect.get_top(),
+        ))
+    }
+
+    pub fn query_circle(&self, circle: &TypedAffine2D<Circle>) -> Vec<usize> {
+        let (cx, cy) = (circle.point.x, circle.point.y);
+        let r2 = circle.shape.radius * circle.shape.radius;
+        let mask: Vec<bool> = self
+            .lefts
+            .iter()
+            .zip(&self.rights)
+            .zip(self.bottoms.iter().zip(&self.tops))
+            .map(|((l, r), (b, t))| {
+                let dx = cx - cx.clamp(*l, *r);
+                let dy = cy - cy.clamp(*b, *t);
+                at_most(dx * dx + dy * dy, r2)
+            })
+            .collect();
+        indices(&mask)
+    }
+}
+
+impl<'a> FromIterator<&'a TypedAffine2D<Rectangle>> for RectangleSet {
+    fn from_iter<I: IntoIterator<Item = &'a TypedAffine2D<Rectangle>>>(rects: I) -> Self {
+        let mut set = RectangleSet::new();
+        for rect in rects {
+            set.push(rect);
+        }
+        set
+    }
+}
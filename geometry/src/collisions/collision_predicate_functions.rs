@@ -7,7 +7,9 @@ use crate::{
         affine::{EmbedInAffine2D, TypedAffine2D},
         circle::Circle,
         line::Line2D,
+        polygon::Polygon,
         rectangle::Rectangle,
+        rounded_rectangle::RoundedRectangle,
         segment::Segment2D,
     },
     vector::Vector2D,
@@ -25,7 +27,7 @@ pub fn check_collision_line_point(l: &TypedAffine2D<Line2D>, point: &Point2D) ->
             || are_equal(norm_angle_radians, f32::consts::FRAC_PI_2)
             || are_equal(norm_angle_radians, 3.0 * f32::consts::FRAC_PI_2);
     }
-    let point_vec = point.clone() - l.point;
+    let point_vec = *point - l.point;
     angle.is_inline_with(&point_vec.get_angle())
 }
 
@@ -45,8 +47,10 @@ pub fn check_collision_circle_point(c: &TypedAffine2D<Circle>, point: &Point2D)
 pub fn check_collision_rect_point(r: &TypedAffine2D<Rectangle>, point: &Point2D) -> bool {
     let left = r.point.x - r.shape.width / 2.0;
     let top = r.point.y - r.shape.height / 2.0;
-    (left..=left + r.shape.width).contains(&point.x)
-        && (top..=top + r.shape.height).contains(&point.y)
+    less_or_equal(left, point.x)
+        && less_or_equal(point.x, left + r.shape.width)
+        && less_or_equal(top, point.y)
+        && less_or_equal(point.y, top + r.shape.height)
 }
 
 pub fn check_collision_line_line(l1: &TypedAffine2D<Line2D>, l2: &TypedAffine2D<Line2D>) -> bool {
@@ -70,8 +74,8 @@ pub fn check_collision_line_segment(
         shape: s.shape.get_line(),
     });
     match p {
-        Some(p) => check_collision_segment_point(&s, &p),
-        None => check_collision_line_point(&l, &s.point),
+        Some(p) => check_collision_segment_point(s, &p),
+        None => check_collision_line_point(l, &s.point),
     }
 }
 
@@ -98,7 +102,7 @@ pub fn check_collision_segment_segment(
 pub fn check_collision_rect_line(r: &TypedAffine2D<Rectangle>, l: &TypedAffine2D<Line2D>) -> bool {
     r.get_segments()
         .iter()
-        .any(|s| check_collision_line_segment(l, &s))
+        .any(|s| check_collision_line_segment(l, s))
 }
 
 pub fn check_collision_rect_segment(
@@ -166,3 +170,185 @@ pub fn check_collision_rect_rect(
         && less_or_equal(r1.get_bottom(), r2.get_top())
         && less_or_equal(r2.get_bottom(), r1.get_top())
 }
+
+pub fn check_collision_polygon_point(p: &TypedAffine2D<Polygon>, point: &Point2D) -> bool {
+    polygon_point_at(&p.point, &p.shape, point)
+}
+
+pub fn check_collision_polygon_segment(
+    p: &TypedAffine2D<Polygon>,
+    s: &TypedAffine2D<Segment2D>,
+) -> bool {
+    polygon_segment_at(&p.point, &p.shape, s)
+}
+
+// The `_at` forms take a polygon together with its position so that polygons stored in
+// an `Affine2D` are checked in place, without cloning their vertices
+
+pub(crate) fn polygon_point_at(at: &Point2D, p: &Polygon, point: &Point2D) -> bool {
+    if p.segments_at(at)
+        .iter()
+        .any(|s| check_collision_segment_point(s, point))
+    {
+        return true;
+    }
+    p.winds_around(&(*point - (*at - Point2D::zero())))
+}
+
+pub(crate) fn polygon_line_at(at: &Point2D, p: &Polygon, l: &TypedAffine2D<Line2D>) -> bool {
+    p.segments_at(at)
+        .iter()
+        .any(|s| check_collision_line_segment(l, s))
+}
+
+pub(crate) fn polygon_segment_at(at: &Point2D, p: &Polygon, s: &TypedAffine2D<Segment2D>) -> bool {
+    let segment_cross_border = p
+        .segments_at(at)
+        .iter()
+        .any(|polygon_segment| check_collision_segment_segment(polygon_segment, s));
+    segment_cross_border || polygon_point_at(at, p, &s.point)
+}
+
+pub(crate) fn polygon_circle_at(at: &Point2D, p: &Polygon, c: &TypedAffine2D<Circle>) -> bool {
+    polygon_point_at(at, p, &c.point)
+        || p.segments_at(at)
+            .iter()
+            .any(|s| check_collision_circle_segment(c, s))
+}
+
+pub(crate) fn polygon_rect_at(at: &Point2D, p: &Polygon, r: &TypedAffine2D<Rectangle>) -> bool {
+    let rect_polygon = Polygon::new(r.get_vertices().to_vec());
+    polygon_polygon_at(at, p, &Point2D::zero(), &rect_polygon)
+}
+
+pub(crate) fn polygon_polygon_at(at1: &Point2D, p1: &Polygon, at2: &Point2D, p2: &Polygon) -> bool {
+    let segments2 = p2.segments_at(at2);
+    let border_cross = p1.segments_at(at1).iter().any(|s1| {
+        segments2
+            .iter()
+            .any(|s2| check_collision_segment_segment(s1, s2))
+    });
+    border_cross
+        || p1
+            .vertices
+            .first()
+            .is_some_and(|v| polygon_point_at(at2, p2, &(*at1 + (*v - Point2D::zero()))))
+        || p2
+            .vertices
+            .first()
+            .is_some_and(|v| polygon_point_at(at1, p1, &(*at2 + (*v - Point2D::zero()))))
+}
+
+fn dist2_rect_segment(r: &TypedAffine2D<Rectangle>, s: &TypedAffine2D<Segment2D>) -> f32 {
+    // clip the segment to the rectangle slabs (Liang-Barsky)
+    let (start, v) = (s.get_start(), s.get_vector());
+    let (mut t_min, mut t_max) = (0.0f32, 1.0f32);
+    for (p, d, min, max) in [
+        (start.x, v.x, r.get_left(), r.get_right()),
+        (start.y, v.y, r.get_bottom(), r.get_top()),
+    ] {
+        if d == 0.0 {
+            if p < min || p > max {
+                t_max = -1.0;
+            }
+            continue;
+        }
+        let (t1, t2) = ((min - p) / d, (max - p) / d);
+        t_min = t_min.max(t1.min(t2));
+        t_max = t_max.min(t1.max(t2));
+    }
+    if t_min <= t_max {
+        return 0.0;
+    }
+    // apart convex shapes are nearest at a vertex of one of them
+    let from_ends = s.get_end_points().map(|p| dist2(&p, &r.closest_point(&p)));
+    let from_corners = r.get_vertices().map(|p| dist2(&p, &s.closest_point(&p)));
+    from_ends
+        .into_iter()
+        .chain(from_corners)
+        .fold(f32::INFINITY, f32::min)
+}
+
+fn dist2_rect_rect(r1: &TypedAffine2D<Rectangle>, r2: &TypedAffine2D<Rectangle>) -> f32 {
+    let dx = ((r1.point.x - r2.point.x).abs() - (r1.shape.width + r2.shape.width) / 2.0).max(0.0);
+    let dy = ((r1.point.y - r2.point.y).abs() - (r1.shape.height + r2.shape.height) / 2.0).max(0.0);
+    dx * dx + dy * dy
+}
+
+pub fn check_collision_rounded_rect_point(
+    rr: &TypedAffine2D<RoundedRectangle>,
+    point: &Point2D,
+) -> bool {
+    less_or_equal(
+        dist2(point, &rr.get_core().closest_point(point)),
+        f32::powi(rr.get_radius(), 2),
+    )
+}
+
+pub fn check_collision_rounded_rect_circle(
+    rr: &TypedAffine2D<RoundedRectangle>,
+    c: &TypedAffine2D<Circle>,
+) -> bool {
+    less_or_equal(
+        dist2(&c.point, &rr.get_core().closest_point(&c.point)),
+        f32::powi(rr.get_radius() + c.shape.radius, 2),
+    )
+}
+
+pub fn check_collision_rounded_rect_line(
+    rr: &TypedAffine2D<RoundedRectangle>,
+    l: &TypedAffine2D<Line2D>,
+) -> bool {
+    let normal = l.shape.get_perpendicular().get_direction();
+    let (min, max) = rr
+        .get_core()
+        .get_vertices()
+        .iter()
+        .map(|v| normal.dot(&(*v - l.point)))
+        .fold((f32::INFINITY, f32::NEG_INFINITY), |(min, max), d| {
+            (min.min(d), max.max(d))
+        });
+    let radius = rr.get_radius();
+    less_or_equal(min, radius) && less_or_equal(-radius, max)
+}
+
+pub fn check_collision_rounded_rect_segment(
+    rr: &TypedAffine2D<RoundedRectangle>,
+    s: &TypedAffine2D<Segment2D>,
+) -> bool {
+    less_or_equal(
+        dist2_rect_segment(&rr.get_core(), s),
+        f32::powi(rr.get_radius(), 2),
+    )
+}
+
+pub fn check_collision_rounded_rect_rect(
+    rr: &TypedAffine2D<RoundedRectangle>,
+    r: &TypedAffine2D<Rectangle>,
+) -> bool {
+    less_or_equal(
+        dist2_rect_rect(&rr.get_core(), r),
+        f32::powi(rr.get_radius(), 2),
+    )
+}
+
+pub fn check_collision_rounded_rect_rounded_rect(
+    rr1: &TypedAffine2D<RoundedRectangle>,
+    rr2: &TypedAffine2D<RoundedRectangle>,
+) -> bool {
+    less_or_equal(
+        dist2_rect_rect(&rr1.get_core(), &rr2.get_core()),
+        f32::powi(rr1.get_radius() + rr2.get_radius(), 2),
+    )
+}
+
+pub(crate) fn rounded_rect_polygon_at(
+    rr: &TypedAffine2D<RoundedRectangle>,
+    at: &Point2D,
+    p: &Polygon,
+) -> bool {
+    polygon_point_at(at, p, &rr.point)
+        || p.segments_at(at)
+            .iter()
+            .any(|s| check_collision_rounded_rect_segment(rr, s))
+}
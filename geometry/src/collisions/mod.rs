@@ -7,7 +7,8 @@ use crate::{
 };
 use collision_predicate_functions::*;
 
-mod collision_predicate_functions;
+pub mod batch;
+pub(crate) mod collision_predicate_functions;
 #[derive(Debug)]
 pub struct CollisionsError;
 pub trait Collisions<T> {
@@ -59,6 +60,9 @@ impl Collisions<Point2D> for Affine2D {
                 &r.embed_affine(&self.point),
                 point,
             )),
+            Shape2D::RoundedRectangle { rounded_rect: rr } => Ok(
+                check_collision_rounded_rect_point(&rr.embed_affine(&self.point), point),
+            ),
             Shape2D::Circle { circle: c } => Ok(check_collision_circle_point(
                 &c.embed_affine(&self.point),
                 point,
@@ -72,6 +76,7 @@ impl Collisions<Point2D> for Affine2D {
                 &TypedAffine2D::new(self.point, s.clone()),
                 point,
             )),
+            Shape2D::Polygon { polygon: p } => Ok(polygon_point_at(&self.point, p, point)),
         }
     }
 }
@@ -107,6 +112,17 @@ impl Collisions<Affine2D> for Affine2D {
                     &c.embed_affine(&other.point),
                     &l.embed_affine(&self.point),
                 )),
+                Shape2D::Polygon { polygon: p } => Ok(polygon_line_at(
+                    &other.point,
+                    p,
+                    &l.embed_affine(&self.point),
+                )),
+                Shape2D::RoundedRectangle { rounded_rect } => {
+                    Ok(check_collision_rounded_rect_line(
+                        &rounded_rect.embed_affine(&other.point),
+                        &l.embed_affine(&self.point),
+                    ))
+                }
             },
             Shape2D::Segment { segment: s } => match &other.shape {
                 Shape2D::Point => Ok(check_collision_segment_point(
@@ -129,6 +145,17 @@ impl Collisions<Affine2D> for Affine2D {
                     &s2.embed_affine(&other.point),
                     &s.embed_affine(&self.point),
                 )),
+                Shape2D::Polygon { polygon } => Ok(polygon_segment_at(
+                    &other.point,
+                    polygon,
+                    &s.embed_affine(&self.point),
+                )),
+                Shape2D::RoundedRectangle { rounded_rect } => {
+                    Ok(check_collision_rounded_rect_segment(
+                        &rounded_rect.embed_affine(&other.point),
+                        &s.embed_affine(&self.point),
+                    ))
+                }
             },
             Shape2D::Circle { circle: c } => match &other.shape {
                 Shape2D::Point => Ok(check_collision_circle_point(
@@ -151,6 +178,17 @@ impl Collisions<Affine2D> for Affine2D {
                     &c.embed_affine(&self.point),
                     &segment.embed_affine(&other.point),
                 )),
+                Shape2D::Polygon { polygon } => Ok(polygon_circle_at(
+                    &other.point,
+                    polygon,
+                    &c.embed_affine(&self.point),
+                )),
+                Shape2D::RoundedRectangle { rounded_rect } => {
+                    Ok(check_collision_rounded_rect_circle(
+                        &rounded_rect.embed_affine(&other.point),
+                        &c.embed_affine(&self.point),
+                    ))
+                }
             },
             Shape2D::Rectangle { rect: r } => match &other.shape {
                 Shape2D::Point => Ok(check_collision_rect_point(
@@ -173,7 +211,80 @@ impl Collisions<Affine2D> for Affine2D {
                     &r.embed_affine(&self.point),
                     &segment.embed_affine(&other.point),
                 )),
+                Shape2D::Polygon { polygon } => Ok(polygon_rect_at(
+                    &other.point,
+                    polygon,
+                    &r.embed_affine(&self.point),
+                )),
+                Shape2D::RoundedRectangle { rounded_rect } => {
+                    Ok(check_collision_rounded_rect_rect(
+                        &rounded_rect.embed_affine(&other.point),
+                        &r.embed_affine(&self.point),
+                    ))
+                }
+            },
+            Shape2D::Polygon { polygon: p } => match &other.shape {
+                Shape2D::Point => Ok(polygon_point_at(&self.point, p, &other.point)),
+                Shape2D::Rectangle { rect } => Ok(polygon_rect_at(
+                    &self.point,
+                    p,
+                    &rect.embed_affine(&other.point),
+                )),
+                Shape2D::Circle { circle } => Ok(polygon_circle_at(
+                    &self.point,
+                    p,
+                    &circle.embed_affine(&other.point),
+                )),
+                Shape2D::Line { line } => Ok(polygon_line_at(
+                    &self.point,
+                    p,
+                    &line.embed_affine(&other.point),
+                )),
+                Shape2D::Segment { segment } => Ok(polygon_segment_at(
+                    &self.point,
+                    p,
+                    &segment.embed_affine(&other.point),
+                )),
+                Shape2D::Polygon { polygon } => {
+                    Ok(polygon_polygon_at(&self.point, p, &other.point, polygon))
+                }
+                Shape2D::RoundedRectangle { rounded_rect } => Ok(rounded_rect_polygon_at(
+                    &rounded_rect.embed_affine(&other.point),
+                    &self.point,
+                    p,
+                )),
             },
+            Shape2D::RoundedRectangle { rounded_rect: rr } => {
+                let rr = rr.embed_affine(&self.point);
+                match &other.shape {
+                    Shape2D::Point => Ok(check_collision_rounded_rect_point(&rr, &other.point)),
+                    Shape2D::Rectangle { rect } => Ok(check_collision_rounded_rect_rect(
+                        &rr,
+                        &rect.embed_affine(&other.point),
+                    )),
+                    Shape2D::RoundedRectangle { rounded_rect } => {
+                        Ok(check_collision_rounded_rect_rounded_rect(
+                            &rr,
+                            &rounded_rect.embed_affine(&other.point),
+                        ))
+                    }
+                    Shape2D::Circle { circle } => Ok(check_collision_rounded_rect_circle(
+                        &rr,
+                        &circle.embed_affine(&other.point),
+                    )),
+                    Shape2D::Line { line } => Ok(check_collision_rounded_rect_line(
+                        &rr,
+                        &line.embed_affine(&other.point),
+                    )),
+                    Shape2D::Segment { segment } => Ok(check_collision_rounded_rect_segment(
+                        &rr,
+                        &segment.embed_affine(&other.point),
+                    )),
+                    Shape2D::Polygon { polygon } => {
+                        Ok(rounded_rect_polygon_at(&rr, &other.point, polygon))
+                    }
+                }
+            }
         }
     }
 }
@@ -1,4 +1,4 @@
-const EPS: f32 = 0.0001;
+pub const EPS: f32 = 0.0001;
 
 pub fn are_equal(a: f32, b: f32) -> bool {
     (a - b).abs() < EPS
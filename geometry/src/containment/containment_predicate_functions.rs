@@ -0,0 +1,253 @@
+use crate::{
+    collisions::collision_predicate_functions::{
+        check_collision_circle_point, check_collision_line_point, check_collision_point_point,
+        check_collision_polygon_point, check_collision_rect_point,
+        check_collision_rounded_rect_point, check_collision_segment_point,
+    },
+    comparators::{are_equal, less_or_equal},
+    point::{Point2D, dist, dist2},
+    shapes::{
+        affine::TypedAffine2D,
+        circle::Circle,
+        line::Line2D,
+        polygon::Polygon,
+        rectangle::Rectangle,
+        rounded_rectangle::RoundedRectangle,
+        segment::{Segment2D, SegmentIntersection},
+    },
+};
+
+pub fn contains_point_point(p1: &Point2D, p2: &Point2D) -> bool {
+    check_collision_point_point(p1, p2)
+}
+
+pub fn contains_line_segment(l: &TypedAffine2D<Line2D>, s: &TypedAffine2D<Segment2D>) -> bool {
+    s.get_end_points()
+        .iter()
+        .all(|p| check_collision_line_point(l, p))
+}
+
+pub fn contains_line_line(l1: &TypedAffine2D<Line2D>, l2: &TypedAffine2D<Line2D>) -> bool {
+    l1.shape.angle.is_inline_with(&l2.shape.angle) && check_collision_line_point(l1, &l2.point)
+}
+
+pub fn contains_segment_segment(
+    s1: &TypedAffine2D<Segment2D>,
+    s2: &TypedAffine2D<Segment2D>,
+) -> bool {
+    s2.get_end_points()
+        .iter()
+        .all(|p| check_collision_segment_point(s1, p))
+}
+
+pub fn contains_circle_segment(c: &TypedAffine2D<Circle>, s: &TypedAffine2D<Segment2D>) -> bool {
+    s.get_end_points()
+        .iter()
+        .all(|p| check_collision_circle_point(c, p))
+}
+
+pub fn contains_circle_circle(c1: &TypedAffine2D<Circle>, c2: &TypedAffine2D<Circle>) -> bool {
+    less_or_equal(
+        dist(&c1.point, &c2.point) + c2.shape.radius,
+        c1.shape.radius,
+    )
+}
+
+pub fn contains_circle_rect(c: &TypedAffine2D<Circle>, r: &TypedAffine2D<Rectangle>) -> bool {
+    r.get_vertices()
+        .iter()
+        .all(|p| check_collision_circle_point(c, p))
+}
+
+pub fn contains_circle_polygon(c: &TypedAffine2D<Circle>, p: &TypedAffine2D<Polygon>) -> bool {
+    p.get_vertices()
+        .iter()
+        .all(|v| check_collision_circle_point(c, v))
+}
+
+pub fn contains_rect_segment(r: &TypedAffine2D<Rectangle>, s: &TypedAffine2D<Segment2D>) -> bool {
+    s.get_end_points()
+        .iter()
+        .all(|p| check_collision_rect_point(r, p))
+}
+
+pub fn contains_rect_circle(r: &TypedAffine2D<Rectangle>, c: &TypedAffine2D<Circle>) -> bool {
+    let radius = c.shape.radius;
+    less_or_equal(r.get_left(), c.point.x - radius)
+        && less_or_equal(c.point.x + radius, r.get_right())
+        && less_or_equal(r.get_bottom(), c.point.y - radius)
+        && less_or_equal(c.point.y + radius, r.get_top())
+}
+
+pub fn contains_rect_rect(r1: &TypedAffine2D<Rectangle>, r2: &TypedAffine2D<Rectangle>) -> bool {
+    less_or_equal(r1.get_left(), r2.get_left())
+        && less_or_equal(r2.get_right(), r1.get_right())
+        && less_or_equal(r1.get_bottom(), r2.get_bottom())
+        && less_or_equal(r2.get_top(), r1.get_top())
+}
+
+pub fn contains_rect_polygon(r: &TypedAffine2D<Rectangle>, p: &TypedAffine2D<Polygon>) -> bool {
+    p.get_vertices()
+        .iter()
+        .all(|v| check_collision_rect_point(r, v))
+}
+
+/// Polygon may be concave, so the segment is split at every crossing with the border
+/// and each piece is checked separately
+pub fn contains_polygon_segment(p: &TypedAffine2D<Polygon>, s: &TypedAffine2D<Segment2D>) -> bool {
+    if !s
+        .get_end_points()
+        .iter()
+        .all(|v| check_collision_polygon_point(p, v))
+    {
+        return false;
+    }
+    let start = s.get_start();
+    let mut cuts: Vec<f32> = p
+        .get_segments()
+        .iter()
+        .filter_map(|edge| s.segment_intersection(edge))
+        .flat_map(|intersection| match intersection {
+            SegmentIntersection::Point(point) => vec![point],
+            SegmentIntersection::Segment(overlap) => overlap.get_end_points().to_vec(),
+        })
+        .map(|point| f32::sqrt(dist2(&start, &point)))
+        .collect();
+    cuts.push(0.0);
+    cuts.push(s.shape.length);
+    cuts.sort_by(f32::total_cmp);
+    cuts.dedup_by(|a, b| are_equal(*a, *b));
+    cuts.windows(2).all(|w| {
+        let middle = s.point_at((w[0] + w[1]) / 2.0 / s.shape.length);
+        check_collision_polygon_point(p, &middle)
+    })
+}
+
+pub fn contains_polygon_circle(p: &TypedAffine2D<Polygon>, c: &TypedAffine2D<Circle>) -> bool {
+    let radius2 = c.shape.radius * c.shape.radius;
+    check_collision_polygon_point(p, &c.point)
+        && p.get_segments()
+            .iter()
+            .all(|edge| less_or_equal(radius2, dist2(&edge.closest_point(&c.point), &c.point)))
+}
+
+pub fn contains_polygon_rect(p: &TypedAffine2D<Polygon>, r: &TypedAffine2D<Rectangle>) -> bool {
+    r.get_segments()
+        .iter()
+        .all(|s| contains_polygon_segment(p, s))
+}
+
+pub fn contains_polygon_polygon(p1: &TypedAffine2D<Polygon>, p2: &TypedAffine2D<Polygon>) -> bool {
+    match p2.shape.vertices.len() {
+        0 => true,
+        1 => check_collision_polygon_point(p1, &p2.get_vertices()[0]),
+        _ => p2
+            .get_segments()
+            .iter()
+            .all(|s| contains_polygon_segment(p1, s)),
+    }
+}
+
+pub fn contains_rounded_rect_segment(
+    rr: &TypedAffine2D<RoundedRectangle>,
+    s: &TypedAffine2D<Segment2D>,
+) -> bool {
+    s.get_end_points()
+        .iter()
+        .all(|p| check_collision_rounded_rect_point(rr, p))
+}
+
+/// The circle center has to stay within the radius difference of the core, or inside
+/// the core shrunk by it when the circle is rounder than the corners
+pub fn contains_rounded_rect_circle(
+    rr: &TypedAffine2D<RoundedRectangle>,
+    c: &TypedAffine2D<Circle>,
+) -> bool {
+    let core = rr.get_core();
+    let slack = rr.get_radius() - c.shape.radius;
+    if slack >= 0.0 {
+        return less_or_equal(
+            dist2(&c.point, &core.closest_point(&c.point)),
+            slack * slack,
+        );
+    }
+    less_or_equal(
+        (c.point.x - core.point.x).abs() - slack,
+        core.shape.width / 2.0,
+    ) && less_or_equal(
+        (c.point.y - core.point.y).abs() - slack,
+        core.shape.height / 2.0,
+    )
+}
+
+pub fn contains_rounded_rect_rect(
+    rr: &TypedAffine2D<RoundedRectangle>,
+    r: &TypedAffine2D<Rectangle>,
+) -> bool {
+    r.get_vertices()
+        .iter()
+        .all(|v| check_collision_rounded_rect_point(rr, v))
+}
+
+pub fn contains_rounded_rect_polygon(
+    rr: &TypedAffine2D<RoundedRectangle>,
+    p: &TypedAffine2D<Polygon>,
+) -> bool {
+    p.get_vertices()
+        .iter()
+        .all(|v| check_collision_rounded_rect_point(rr, v))
+}
+
+/// Rounded rectangles are exactly covered by their bands and corner circles, so any
+/// shape containing all of them contains the rounded rectangle
+fn contains_rounded_rect_parts(
+    rr: &TypedAffine2D<RoundedRectangle>,
+    contains_rect: impl Fn(&TypedAffine2D<Rectangle>) -> bool,
+    contains_circle: impl Fn(&TypedAffine2D<Circle>) -> bool,
+) -> bool {
+    rr.get_bands().iter().all(contains_rect) && rr.get_corner_circles().iter().all(contains_circle)
+}
+
+pub fn contains_rounded_rect_rounded_rect(
+    rr1: &TypedAffine2D<RoundedRectangle>,
+    rr2: &TypedAffine2D<RoundedRectangle>,
+) -> bool {
+    contains_rounded_rect_parts(
+        rr2,
+        |r| contains_rounded_rect_rect(rr1, r),
+        |c| contains_rounded_rect_circle(rr1, c),
+    )
+}
+
+pub fn contains_circle_rounded_rect(
+    c: &TypedAffine2D<Circle>,
+    rr: &TypedAffine2D<RoundedRectangle>,
+) -> bool {
+    contains_rounded_rect_parts(
+        rr,
+        |r| contains_circle_rect(c, r),
+        |c2| contains_circle_circle(c, c2),
+    )
+}
+
+pub fn contains_rect_rounded_rect(
+    r: &TypedAffine2D<Rectangle>,
+    rr: &TypedAffine2D<RoundedRectangle>,
+) -> bool {
+    contains_rounded_rect_parts(
+        rr,
+        |r2| contains_rect_rect(r, r2),
+        |c| contains_rect_circle(r, c),
+    )
+}
+
+pub fn contains_polygon_rounded_rect(
+    p: &TypedAffine2D<Polygon>,
+    rr: &TypedAffine2D<RoundedRectangle>,
+) -> bool {
+    contains_rounded_rect_parts(
+        rr,
+        |r| contains_polygon_rect(p, r),
+        |c| contains_polygon_circle(p, c),
+    )
+}
@@ -0,0 +1,212 @@
+use crate::{
+    collisions::collision_predicate_functions::*,
+    point::*,
+    shapes::{
+        Shape2D, Shape2DType,
+        affine::{Affine2D, EmbedInAffine2D, TypedAffine2D},
+    },
+};
+use containment_predicate_functions::*;
+
+mod containment_predicate_functions;
+#[derive(Debug)]
+pub struct ContainsError;
+/// Checks whether shape lies entirely within `self`, touching the border counts as contained
+pub trait Contains<T> {
+    fn contains(&self, shape: &T) -> Result<bool, ContainsError>;
+}
+
+impl Contains<Point2D> for Point2D {
+    fn contains(&self, point: &Point2D) -> Result<bool, ContainsError> {
+        Ok(contains_point_point(self, point))
+    }
+}
+
+impl<T> Contains<Point2D> for TypedAffine2D<T>
+where
+    T: Shape2DType,
+{
+    fn contains(&self, point: &Point2D) -> Result<bool, ContainsError> {
+        let aff: Affine2D = self.clone().into();
+        aff.contains(point)
+    }
+}
+
+impl<T> Contains<Affine2D> for TypedAffine2D<T>
+where
+    T: Shape2DType,
+{
+    fn contains(&self, aff2: &Affine2D) -> Result<bool, ContainsError> {
+        let aff1: Affine2D = self.clone().into();
+        aff1.contains(aff2)
+    }
+}
+
+impl<S, T> Contains<TypedAffine2D<S>> for TypedAffine2D<T>
+where
+    T: Shape2DType,
+    S: Shape2DType,
+{
+    fn contains(&self, t_aff2: &TypedAffine2D<S>) -> Result<bool, ContainsError> {
+        let aff1: Affine2D = self.clone().into();
+        let aff2: Affine2D = t_aff2.clone().into();
+        aff1.contains(&aff2)
+    }
+}
+
+impl Contains<Point2D> for Affine2D {
+    fn contains(&self, point: &Point2D) -> Result<bool, ContainsError> {
+        match &self.shape {
+            Shape2D::Rectangle { rect: r } => Ok(check_collision_rect_point(
+                &r.embed_affine(&self.point),
+                point,
+            )),
+            Shape2D::Circle { circle: c } => Ok(check_collision_circle_point(
+                &c.embed_affine(&self.point),
+                point,
+            )),
+            Shape2D::RoundedRectangle { rounded_rect: rr } => Ok(
+                check_collision_rounded_rect_point(&rr.embed_affine(&self.point), point),
+            ),
+            Shape2D::Point => Ok(contains_point_point(&self.point, point)),
+            Shape2D::Line { line: l } => Ok(check_collision_line_point(
+                &l.embed_affine(&self.point),
+                point,
+            )),
+            Shape2D::Segment { segment: s } => Ok(check_collision_segment_point(
+                &s.embed_affine(&self.point),
+                point,
+            )),
+            Shape2D::Polygon { polygon: p } => Ok(check_collision_polygon_point(
+                &p.embed_affine(&self.point),
+                point,
+            )),
+        }
+    }
+}
+
+impl Contains<Affine2D> for Affine2D {
+    fn contains(&self, other: &Affine2D) -> Result<bool, ContainsError> {
+        if let Shape2D::Point = &other.shape {
+            return self.contains(&other.point);
+        }
+        match &self.shape {
+            Shape2D::Point => Ok(false),
+            Shape2D::Line { line: l } => match &other.shape {
+                Shape2D::Line { line: l2 } => Ok(contains_line_line(
+                    &l.embed_affine(&self.point),
+                    &l2.embed_affine(&other.point),
+                )),
+                Shape2D::Segment { segment: s } => Ok(contains_line_segment(
+                    &l.embed_affine(&self.point),
+                    &s.embed_affine(&other.point),
+                )),
+                _ => Ok(false),
+            },
+            Shape2D::Segment { segment: s } => match &other.shape {
+                Shape2D::Segment { segment: s2 } => Ok(contains_segment_segment(
+                    &s.embed_affine(&self.point),
+                    &s2.embed_affine(&other.point),
+                )),
+                _ => Ok(false),
+            },
+            Shape2D::Circle { circle: c } => match &other.shape {
+                Shape2D::Segment { segment } => Ok(contains_circle_segment(
+                    &c.embed_affine(&self.point),
+                    &segment.embed_affine(&other.point),
+                )),
+                Shape2D::Circle { circle } => Ok(contains_circle_circle(
+                    &c.embed_affine(&self.point),
+                    &circle.embed_affine(&other.point),
+                )),
+                Shape2D::Rectangle { rect } => Ok(contains_circle_rect(
+                    &c.embed_affine(&self.point),
+                    &rect.embed_affine(&other.point),
+                )),
+                Shape2D::Polygon { polygon } => Ok(contains_circle_polygon(
+                    &c.embed_affine(&self.point),
+                    &polygon.embed_affine(&other.point),
+                )),
+                Shape2D::RoundedRectangle { rounded_rect } => Ok(contains_circle_rounded_rect(
+                    &c.embed_affine(&self.point),
+                    &rounded_rect.embed_affine(&other.point),
+                )),
+                _ => Ok(false),
+            },
+            Shape2D::Rectangle { rect: r } => match &other.shape {
+                Shape2D::Segment { segment } => Ok(contains_rect_segment(
+                    &r.embed_affine(&self.point),
+                    &segment.embed_affine(&other.point),
+                )),
+                Shape2D::Circle { circle } => Ok(contains_rect_circle(
+                    &r.embed_affine(&self.point),
+                    &circle.embed_affine(&other.point),
+                )),
+                Shape2D::Rectangle { rect } => Ok(contains_rect_rect(
+                    &r.embed_affine(&self.point),
+                    &rect.embed_affine(&other.point),
+                )),
+                Shape2D::Polygon { polygon } => Ok(contains_rect_polygon(
+                    &r.embed_affine(&self.point),
+                    &polygon.embed_affine(&other.point),
+                )),
+                Shape2D::RoundedRectangle { rounded_rect } => Ok(contains_rect_rounded_rect(
+                    &r.embed_affine(&self.point),
+                    &rounded_rect.embed_affine(&other.point),
+                )),
+                _ => Ok(false),
+            },
+            Shape2D::Polygon { polygon: p } => match &other.shape {
+                Shape2D::Segment { segment } => Ok(contains_polygon_segment(
+                    &p.embed_affine(&self.point),
+                    &segment.embed_affine(&other.point),
+                )),
+                Shape2D::Circle { circle } => Ok(contains_polygon_circle(
+                    &p.embed_affine(&self.point),
+                    &circle.embed_affine(&other.point),
+                )),
+                Shape2D::Rectangle { rect } => Ok(contains_polygon_rect(
+                    &p.embed_affine(&self.point),
+                    &rect.embed_affine(&other.point),
+                )),
+                Shape2D::Polygon { polygon } => Ok(contains_polygon_polygon(
+                    &p.embed_affine(&self.point),
+                    &polygon.embed_affine(&other.point),
+                )),
+                Shape2D::RoundedRectangle { rounded_rect } => Ok(contains_polygon_rounded_rect(
+                    &p.embed_affine(&self.point),
+                    &rounded_rect.embed_affine(&other.point),
+                )),
+                _ => Ok(false),
+            },
+            Shape2D::RoundedRectangle { rounded_rect: rr } => {
+                let rr = rr.embed_affine(&self.point);
+                match &other.shape {
+                    Shape2D::Segment { segment } => Ok(contains_rounded_rect_segment(
+                        &rr,
+                        &segment.embed_affine(&other.point),
+                    )),
+                    Shape2D::Circle { circle } => Ok(contains_rounded_rect_circle(
+                        &rr,
+                        &circle.embed_affine(&other.point),
+                    )),
+                    Shape2D::Rectangle { rect } => Ok(contains_rounded_rect_rect(
+                        &rr,
+                        &rect.embed_affine(&other.point),
+                    )),
+                    Shape2D::RoundedRectangle { rounded_rect } => {
+                        Ok(contains_rounded_rect_rounded_rect(
+                            &rr,
+                            &rounded_rect.embed_affine(&other.point),
+                        ))
+                    }
+                    Shape2D::Polygon { polygon } => Ok(contains_rounded_rect_polygon(
+                        &rr,
+                        &polygon.embed_affine(&other.point),
+                    )),
+                    _ => Ok(false),
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,160 @@
+use std::{io, path::Path};
+
+use crate::{
+    collisions::{Collisions, CollisionsError, batch::query_point_many},
+    point::Point2D,
+    shapes::affine::Affine2D,
+    svg::{document, frame_of, framed_element, line_element},
+    vector::Vector2D,
+};
+
+/// Shapes and collision query results collected while debugging, written out as an
+/// SVG image. Sizes of markers and arrows follow the size of the scene.
+#[derive(Debug, Clone, Default)]
+pub struct DebugScene {
+    pub shapes: Vec<Affine2D>,
+    /// Indices of shapes reported as hit, drawn highlighted
+    pub hits: Vec<usize>,
+    pub contacts: Vec<Point2D>,
+    /// Normals as a point and the direction drawn from it
+    pub normals: Vec<(Point2D, Vector2D)>,
+    /// Pairs of closest points, joined by a dashed line
+    pub closest_points: Vec<(Point2D, Point2D)>,
+    pub queries: Vec<Point2D>,
+}
+
+impl DebugScene {
+    pub fn new() -> DebugScene {
+        DebugScene::default()
+    }
+
+    pub fn add_shape(&mut self, shape: Affine2D) -> usize {
+        self.shapes.push(shape);
+        self.shapes.len() - 1
+    }
+
+    pub fn mark_hit(&mut self, index: usize) {
+        if !self.hits.contains(&index) {
+            self.hits.push(index);
+        }
+    }
+
+    pub fn add_contact(&mut self, point: Point2D) {
+        self.contacts.push(point);
+    }
+
+    pub fn add_normal(&mut self, point: Point2D, normal: Vector2D) {
+        self.normals.push((point, normal));
+    }
+
+    pub fn add_closest_points(&mut self, a: Point2D, b: Point2D) {
+        self.closest_points.push((a, b));
+    }
+
+    /// Checks two shapes of the scene for collision and marks both as hit when they collide
+    pub fn record_collision(&mut self, a: usize, b: usize) -> Result<bool, CollisionsError> {
+        let hit = self.shapes[a].check_collision(&self.shapes[b])?;
+        if hit {
+            self.mark_hit(a);
+            self.mark_hit(b);
+        }
+        Ok(hit)
+    }
+
+    /// Records the query point and marks every shape containing it as hit, returns their
+    /// indices
+    pub fn record_point_query(&mut self, point: &Point2D) -> Result<Vec<usize>, CollisionsError> {
+        self.queries.push(*point);
+        let found = query_point_many(&self.shapes, point)?;
+        for i in &found {
+            self.mark_hit(*i);
+        }
+        Ok(found)
+    }
+
+    /// Self-contained SVG image of the scene with the y axis pointing up
+    pub fn to_svg(&self) -> String {
+        let mut points: Vec<Point2D> = self.contacts.clone();
+        points.extend(&self.queries);
+        points.extend(self.closest_points.iter().flat_map(|(a, b)| [*a, *b]));
+        points.extend(self.normals.iter().map(|(p, _)| *p));
+        let bounds = frame_of(&self.shapes, &points, 0.0);
+        let unit = bounds.shape.width.max(bounds.shape.height).max(1.0) / 100.0;
+        let frame = bounds.inflated(10.0 * unit);
+        let width = unit / 4.0;
+        let stroke = |color: &str, fill: &str| {
+            format!(
+                r#"<g fill="{fill}" stroke="{color}" stroke-width="{width}" stroke-linecap="round">"#
+            )
+        };
+        let dot = |p: &Point2D, r: f32| format!(r#"<circle cx="{}" cy="{}" r="{r}"/>"#, p.x, p.y);
+        let mut content = vec![stroke("gray", "none")];
+        content.extend(
+            self.shapes
+                .iter()
+                .enumerate()
+                .filter(|(i, _)| !self.hits.contains(i))
+                .map(|(_, s)| framed_element(s, &frame)),
+        );
+        content.push("</g>".to_string());
+        content.push(stroke("red", "#ff000026"));
+        content.extend(
+            self.hits
+                .iter()
+                .filter_map(|i| self.shapes.get(*i))
+                .map(|s| framed_element(s, &frame)),
+        );
+        content.push("</g>".to_string());
+        content.push(format!(
+            r#"<g stroke="blue" stroke-width="{width}" stroke-dasharray="{unit} {unit}">"#
+        ));
+        for (a, b) in &self.closest_points {
+            content.push(line_element(a, b));
+        }
+        content.push("</g>".to_string());
+        content.push(r#"<g fill="blue">"#.to_string());
+        for (a, b) in &self.closest_points {
+            content.push(dot(a, unit));
+            content.push(dot(b, unit));
+        }
+        content.push("</g>".to_string());
+        content.push(stroke("green", "green"));
+        for (p, normal) in &self.normals {
+            content.extend(arrow(p, normal, 10.0 * unit));
+        }
+        content.push("</g>".to_string());
+        content.push(r#"<g fill="orange">"#.to_string());
+        content.extend(self.contacts.iter().map(|p| dot(p, unit)));
+        content.push("</g>".to_string());
+        content.push(stroke("purple", "none"));
+        for q in &self.queries {
+            let (dx, dy) = (Vector2D { x: unit, y: 0.0 }, Vector2D { x: 0.0, y: unit });
+            for (a, b) in [(*q - dx.clone(), *q + dx), (*q - dy.clone(), *q + dy)] {
+                content.push(line_element(&a, &b));
+            }
+        }
+        content.push("</g>".to_string());
+        document(&frame, &content)
+    }
+
+    pub fn write(&self, path: impl AsRef<Path>) -> io::Result<()> {
+        std::fs::write(path, self.to_svg())
+    }
+}
+
+fn arrow(p: &Point2D, direction: &Vector2D, length: f32) -> Vec<String> {
+    let Some(d) = direction.normalized() else {
+        return vec![];
+    };
+    let tip = *p + d.clone() * length;
+    let head = length / 4.0;
+    let side = Vector2D { x: -d.y, y: d.x } * (head / 2.0);
+    let (left, right) = (tip - d.clone() * head + side.clone(), tip - d * head - side);
+    vec![
+        line_element(p, &tip),
+        format!(
+            r#"<polygon points="{},{} {},{} {},{}"/>"#,
+            tip.x, tip.y, left.x, left.y, right.x, right.y
+        ),
+    ]
+}
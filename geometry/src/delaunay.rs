@@ -0,0 +1,298 @@
+use std::collections::{BTreeSet, HashMap};
+
+use crate::{
+    point::{Point2D, dist2},
+    shapes::{affine::TypedAffine2D, polygon::Polygon, rectangle::Rectangle},
+    triangulation::TriangleMesh,
+    vector::Vector2D,
+};
+
+/// Delaunay triangulation of a point set, no point lies inside the circumcircle of any triangle
+#[derive(Debug, Clone)]
+pub struct DelaunayTriangulation {
+    pub points: Vec<Point2D>,
+    /// Counter-clockwise triangles given as indices into `points`
+    pub triangles: Vec<[usize; 3]>,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Circumcircle {
+    x: f64,
+    y: f64,
+    radius2: f64,
+}
+
+impl Circumcircle {
+    fn new(a: (f64, f64), b: (f64, f64), c: (f64, f64)) -> Circumcircle {
+        let d = 2.0 * (a.0 * (b.1 - c.1) + b.0 * (c.1 - a.1) + c.0 * (a.1 - b.1));
+        let (a2, b2, c2) = (
+            a.0 * a.0 + a.1 * a.1,
+            b.0 * b.0 + b.1 * b.1,
+            c.0 * c.0 + c.1 * c.1,
+        );
+        let x = (a2 * (b.1 - c.1) + b2 * (c.1 - a.1) + c2 * (a.1 - b.1)) / d;
+        let y = (a2 * (c.0 - b.0) + b2 * (a.0 - c.0) + c2 * (b.0 - a.0)) / d;
+        Circumcircle {
+            x,
+            y,
+            radius2: (a.0 - x).powi(2) + (a.1 - y).powi(2),
+        }
+    }
+
+    fn contains(&self, p: (f64, f64)) -> bool {
+        (p.0 - self.x).powi(2) + (p.1 - self.y).powi(2) < self.radius2
+    }
+}
+
+fn orient(a: (f64, f64), b: (f64, f64), c: (f64, f64)) -> f64 {
+    (b.0 - a.0) * (c.1 - a.1) - (b.1 - a.1) * (c.0 - a.0)
+}
+
+/// Flags telling which points are triangulated, a point is left out when it equals an
+/// earlier point that was kept
+fn kept_points(points: &[Point2D]) -> Vec<bool> {
+    let mut kept_indices: Vec<usize> = vec![];
+    let mut kept = vec![false; points.len()];
+    for (i, p) in points.iter().enumerate() {
+        if !kept_indices.iter().any(|&k| points[k] == *p) {
+            kept_indices.push(i);
+            kept[i] = true;
+        }
+    }
+    kept
+}
+
+/// What a point has to lie in to conflict with a triangle
+#[derive(Debug, Clone, Copy)]
+enum Conflict {
+    Circle(Circumcircle),
+    /// Triangle `[a, b, infinity]`, open half-plane left of `a -> b` and the inside
+    /// of the segment itself
+    HalfPlane((f64, f64), (f64, f64)),
+}
+
+impl Conflict {
+    fn contains(&self, p: (f64, f64)) -> bool {
+        match self {
+            Conflict::Circle(circle) => circle.contains(p),
+            Conflict::HalfPlane(a, b) => {
+                let side = orient(*a, *b, p);
+                side > 0.0
+                    || (side == 0.0 && {
+                        let t = (p.0 - a.0) * (b.0 - a.0) + (p.1 - a.1) * (b.1 - a.1);
+                        t > 0.0 && t < (b.0 - a.0).powi(2) + (b.1 - a.1).powi(2)
+                    })
+            }
+        }
+    }
+}
+
+impl DelaunayTriangulation {
+    /// Bowyer-Watson algorithm with a single vertex at infinity joined to every hull
+    /// edge, so hull triangles are never lost to a finite super triangle. Points equal
+    /// to an earlier point (see `Point2D` equality) are left out of the triangulation.
+    pub fn new(points: &[Point2D]) -> DelaunayTriangulation {
+        let coords: Vec<(f64, f64)> = points.iter().map(|p| (p.x as f64, p.y as f64)).collect();
+        let n = coords.len();
+        let empty = DelaunayTriangulation {
+            points: points.to_vec(),
+            triangles: vec![],
+        };
+        let kept = kept_points(points);
+        let order: Vec<usize> = (0..n).filter(|&i| kept[i]).collect();
+        // first triangle from the first two points and the first point off their line
+        if order.len() < 3 {
+            return empty;
+        }
+        let (a, b) = (order[0], order[1]);
+        let Some(&c) = order[2..]
+            .iter()
+            .find(|&&c| orient(coords[a], coords[b], coords[c]) != 0.0)
+        else {
+            return empty;
+        };
+        let first = if orient(coords[a], coords[b], coords[c]) > 0.0 {
+            [a, b, c]
+        } else {
+            [a, c, b]
+        };
+        let infinity = n;
+        let conflict = |t: &[usize; 3]| match t.iter().position(|&v| v == infinity) {
+            None => Conflict::Circle(Circumcircle::new(coords[t[0]], coords[t[1]], coords[t[2]])),
+            Some(k) => Conflict::HalfPlane(coords[t[(k + 1) % 3]], coords[t[(k + 2) % 3]]),
+        };
+        let mut triangles: Vec<([usize; 3], Conflict)> = vec![(first, conflict(&first))];
+        for k in 0..3 {
+            let ghost = [first[(k + 1) % 3], first[k], infinity];
+            triangles.push((ghost, conflict(&ghost)));
+        }
+
+        for &i in order.iter().filter(|&&i| !first.contains(&i)) {
+            let p = coords[i];
+            let (bad, good): (Vec<_>, Vec<_>) =
+                triangles.into_iter().partition(|(_, c)| c.contains(p));
+            triangles = good;
+            // edges of the cavity are those used by exactly one removed triangle
+            let mut edge_count: HashMap<(usize, usize), usize> = HashMap::new();
+            for (t, _) in &bad {
+                for k in 0..3 {
+                    let (a, b) = (t[k], t[(k + 1) % 3]);
+                    *edge_count.entry((a.min(b), a.max(b))).or_default() += 1;
+                }
+            }
+            for (t, _) in &bad {
+                for k in 0..3 {
+                    let (a, b) = (t[k], t[(k + 1) % 3]);
+                    if edge_count[&(a.min(b), a.max(b))] == 1 {
+                        let triangle = [a, b, i];
+                        triangles.push((triangle, conflict(&triangle)));
+                    }
+                }
+            }
+        }
+        DelaunayTriangulation {
+            points: points.to_vec(),
+            triangles: triangles
+                .into_iter()
+                .map(|(t, _)| t)
+                .filter(|t| !t.contains(&infinity))
+                .collect(),
+        }
+    }
+
+    /// Unique edges as pairs of point indices, smaller index first
+    pub fn get_edges(&self) -> Vec<(usize, usize)> {
+        let edges: BTreeSet<(usize, usize)> = self
+            .triangles
+            .iter()
+            .flat_map(|t| (0..3).map(move |k| (t[k].min(t[(k + 1) % 3]), t[k].max(t[(k + 1) % 3]))))
+            .collect();
+        edges.into_iter().collect()
+    }
+
+    pub fn get_neighbours(&self) -> Vec<Vec<usize>> {
+        let mut neighbours = vec![vec![]; self.points.len()];
+        for (a, b) in self.get_edges() {
+            neighbours[a].push(b);
+            neighbours[b].push(a);
+        }
+        neighbours
+    }
+
+    pub fn to_mesh(&self) -> TriangleMesh {
+        TriangleMesh {
+            vertices: self.points.clone(),
+            indices: self.triangles.iter().flatten().map(|&i| i as u32).collect(),
+        }
+    }
+}
+
+/// Voronoi diagram of a point set restricted to a bounding rectangle
+#[derive(Debug, Clone)]
+pub struct VoronoiDiagram {
+    pub triangulation: DelaunayTriangulation,
+    /// Cell of every point in world coordinates, cells of duplicated points are empty
+    pub cells: Vec<Polygon>,
+    /// Points sharing a cell edge with each point, consecutive points for collinear input
+    pub neighbours: Vec<Vec<usize>>,
+}
+
+impl VoronoiDiagram {
+    pub fn new(points: &[Point2D], bounds: &TypedAffine2D<Rectangle>) -> VoronoiDiagram {
+        let triangulation = DelaunayTriangulation::new(points);
+        let kept = kept_points(points);
+        let neighbours = if triangulation.triangles.is_empty() {
+            collinear_neighbours(points, &kept)
+        } else {
+            triangulation.get_neighbours()
+        };
+        let cells = points
+            .iter()
+            .enumerate()
+            .map(|(i, site)| {
+                if !kept[i] {
+                    return Polygon::new(vec![]);
+                }
+                let mut cell = bounds.get_vertices().to_vec();
+                for &j in &neighbours[i] {
+                    cell = clip_to_closer_half(&cell, site, &points[j]);
+                }
+                Polygon::new(cell)
+            })
+            .collect();
+        VoronoiDiagram {
+            triangulation,
+            cells,
+            neighbours,
+        }
+    }
+
+    /// Index of the point nearest to `p`, found by walking from neighbour to neighbour
+    pub fn nearest_site(&self, p: &Point2D) -> Option<usize> {
+        let points = &self.triangulation.points;
+        let neighbours = &self.neighbours;
+        if points.is_empty() {
+            return None;
+        }
+        // the first point is never left out as a duplicate
+        let mut current = 0;
+        loop {
+            let closer = neighbours[current]
+                .iter()
+                .copied()
+                .filter(|&j| dist2(&points[j], p) < dist2(&points[current], p))
+                .min_by(|&i, &j| dist2(&points[i], p).total_cmp(&dist2(&points[j], p)));
+            match closer {
+                Some(next) => current = next,
+                None => return Some(current),
+            }
+        }
+    }
+}
+
+/// Neighbours of kept points that do not span a triangle, each point is joined to the
+/// ones before and after it along their common line
+fn collinear_neighbours(points: &[Point2D], kept: &[bool]) -> Vec<Vec<usize>> {
+    let mut order: Vec<usize> = (0..points.len()).filter(|&i| kept[i]).collect();
+    let mut neighbours = vec![vec![]; points.len()];
+    let Some(&first) = order.first() else {
+        return neighbours;
+    };
+    let far = order
+        .iter()
+        .copied()
+        .max_by(|&i, &j| {
+            dist2(&points[first], &points[i]).total_cmp(&dist2(&points[first], &points[j]))
+        })
+        .unwrap_or(first);
+    let direction = points[far] - points[first];
+    order.sort_by(|&i, &j| {
+        direction
+            .dot(&(points[i] - points[first]))
+            .total_cmp(&direction.dot(&(points[j] - points[first])))
+    });
+    for pair in order.windows(2) {
+        neighbours[pair[0]].push(pair[1]);
+        neighbours[pair[1]].push(pair[0]);
+    }
+    neighbours
+}
+
+/// Part of a convex polygon closer to `site` than to `other` (Sutherland-Hodgman)
+fn clip_to_closer_half(polygon: &[Point2D], site: &Point2D, other: &Point2D) -> Vec<Point2D> {
+    let normal: Vector2D = *other - *site;
+    let middle = *site + normal.clone() / 2.0;
+    let side = |p: &Point2D| normal.dot(&(*p - middle));
+    let mut clipped = Vec::with_capacity(polygon.len() + 1);
+    for (k, a) in polygon.iter().enumerate() {
+        let b = &polygon[(k + 1) % polygon.len()];
+        let (sa, sb) = (side(a), side(b));
+        if sa <= 0.0 {
+            clipped.push(*a);
+        }
+        if (sa < 0.0 && sb > 0.0) || (sa > 0.0 && sb < 0.0) {
+            clipped.push(*a + (*b - *a) * (sa / (sa - sb)));
+        }
+    }
+    clipped
+}
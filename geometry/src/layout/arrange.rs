@@ -0,0 +1,128 @@
+use crate::{
+    bounding::{BoundingBox, bounding_box_of_points},
+    shapes::{Shape2DType, affine::TypedAffine2D, rectangle::Rectangle},
+    vector::Vector2D,
+};
+
+/// Side or center line of the selection bounding box that shapes are moved to
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Alignment {
+    Left,
+    Right,
+    Top,
+    Bottom,
+    /// Centers on the vertical line through the selection center
+    HorizontalCenter,
+    /// Centers on the horizontal line through the selection center
+    VerticalCenter,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Axis {
+    Horizontal,
+    Vertical,
+}
+
+fn selection_bounds<T: Shape2DType>(
+    shapes: &[TypedAffine2D<T>],
+) -> (
+    Vec<Option<TypedAffine2D<Rectangle>>>,
+    Option<TypedAffine2D<Rectangle>>,
+) {
+    let boxes: Vec<Option<TypedAffine2D<Rectangle>>> =
+        shapes.iter().map(|s| s.bounding_box()).collect();
+    let corners: Vec<_> = boxes
+        .iter()
+        .flatten()
+        .flat_map(|b| b.get_vertices())
+        .collect();
+    (boxes, bounding_box_of_points(&corners))
+}
+
+/// Moves shapes so that their bounding boxes line up with the side or center of the
+/// selection bounding box, unbounded shapes are left in place
+pub fn align<T: Shape2DType>(
+    shapes: &[TypedAffine2D<T>],
+    alignment: Alignment,
+) -> Vec<TypedAffine2D<T>> {
+    let (boxes, selection) = selection_bounds(shapes);
+    let Some(selection) = selection else {
+        return shapes.to_vec();
+    };
+    shapes
+        .iter()
+        .zip(boxes)
+        .map(|(shape, b)| {
+            let Some(b) = b else {
+                return shape.clone();
+            };
+            let shift = match alignment {
+                Alignment::Left => Vector2D {
+                    x: selection.get_left() - b.get_left(),
+                    y: 0.0,
+                },
+                Alignment::Right => Vector2D {
+                    x: selection.get_right() - b.get_right(),
+                    y: 0.0,
+                },
+                Alignment::HorizontalCenter => Vector2D {
+                    x: selection.point.x - b.point.x,
+                    y: 0.0,
+                },
+                Alignment::Top => Vector2D {
+                    x: 0.0,
+                    y: selection.get_top() - b.get_top(),
+                },
+                Alignment::Bottom => Vector2D {
+                    x: 0.0,
+                    y: selection.get_bottom() - b.get_bottom(),
+                },
+                Alignment::VerticalCenter => Vector2D {
+                    x: 0.0,
+                    y: selection.point.y - b.point.y,
+                },
+            };
+            TypedAffine2D::new(shape.point + shift, shape.shape.clone())
+        })
+        .collect()
+}
+
+/// Moves shapes along `axis` so that the gaps between neighbouring bounding boxes are
+/// equal, the outermost sides of the selection stay where they are. Shapes keep their
+/// order by left (or bottom) side, unbounded shapes are left in place.
+pub fn distribute<T: Shape2DType>(
+    shapes: &[TypedAffine2D<T>],
+    axis: Axis,
+) -> Vec<TypedAffine2D<T>> {
+    let (boxes, selection) = selection_bounds(shapes);
+    let Some(selection) = selection else {
+        return shapes.to_vec();
+    };
+    let extent = |b: &TypedAffine2D<Rectangle>| match axis {
+        Axis::Horizontal => (b.get_left(), b.shape.width),
+        Axis::Vertical => (b.get_bottom(), b.shape.height),
+    };
+    let mut order: Vec<(usize, f32, f32)> = boxes
+        .iter()
+        .enumerate()
+        .filter_map(|(i, b)| b.as_ref().map(|b| (i, extent(b).0, extent(b).1)))
+        .collect();
+    if order.len() < 2 {
+        return shapes.to_vec();
+    }
+    order.sort_by(|a, b| a.1.total_cmp(&b.1).then(a.0.cmp(&b.0)));
+    let (start, span) = extent(&selection);
+    let sizes: f32 = order.iter().map(|(_, _, size)| size).sum();
+    let gap = (span - sizes) / (order.len() - 1) as f32;
+    let mut result = shapes.to_vec();
+    let mut position = start;
+    for (i, min, size) in order {
+        let delta = position - min;
+        match axis {
+            Axis::Horizontal => result[i].point.x += delta,
+            Axis::Vertical => result[i].point.y += delta,
+        }
+        position += size + gap;
+    }
+    result
+}
@@ -0,0 +1,302 @@
+use crate::{
+    angle::Angle,
+    bounding::{BoundingBox, bounding_box_of_points},
+    point::Point2D,
+    shapes::affine::Affine2D,
+    vector::Vector2D,
+};
+
+/// Depth at which quadtree cells stop splitting, so that coincident nodes share a cell
+const MAX_TREE_DEPTH: usize = 24;
+
+/// Nodes closer than this repel each other as if they were this far apart
+const MIN_DISTANCE: f32 = 0.01;
+
+/// Fraction of the net force applied as displacement, small enough for the layout to
+/// settle instead of oscillating around the equilibrium
+const STEP_SIZE: f32 = 0.1;
+
+#[derive(Debug, Clone)]
+pub struct ForceLayoutSettings {
+    /// Preferred gap between the borders of connected nodes
+    pub edge_length: f32,
+    /// Pull of every node towards `center`, keeps components together
+    pub gravity: f32,
+    pub center: Point2D,
+    /// Barnes-Hut accuracy, cells seen under a smaller size to distance ratio are taken
+    /// as a single body, 0 computes every pair exactly
+    pub theta: f32,
+    /// Largest displacement of a node during the first step
+    pub initial_temperature: f32,
+    /// Factor applied to the temperature after every step
+    pub cooling: f32,
+    /// Temperature never cools below this, so later changes still settle
+    pub min_temperature: f32,
+    /// Layout is converged once no node moves further than this in a step
+    pub tolerance: f32,
+}
+
+impl Default for ForceLayoutSettings {
+    fn default() -> Self {
+        ForceLayoutSettings {
+            edge_length: 50.0,
+            gravity: 0.01,
+            center: Point2D::zero(),
+            theta: 0.8,
+            initial_temperature: 50.0,
+            cooling: 0.95,
+            min_temperature: 0.5,
+            tolerance: 0.05,
+        }
+    }
+}
+
+/// Fruchterman-Reingold layout with Barnes-Hut approximated repulsion, run step by step
+#[derive(Debug, Clone)]
+pub struct ForceLayout {
+    pub nodes: Vec<Affine2D>,
+    pub edges: Vec<(usize, usize)>,
+    /// Pinned nodes push and pull others but never move
+    pub pinned: Vec<bool>,
+    pub settings: ForceLayoutSettings,
+    temperature: f32,
+}
+
+impl ForceLayout {
+    pub fn new(
+        nodes: Vec<Affine2D>,
+        edges: Vec<(usize, usize)>,
+        settings: ForceLayoutSettings,
+    ) -> ForceLayout {
+        ForceLayout {
+            pinned: vec![false; nodes.len()],
+            temperature: settings.initial_temperature,
+            nodes,
+            edges,
+            settings,
+        }
+    }
+
+    pub fn add_node(&mut self, node: Affine2D) -> usize {
+        self.nodes.push(node);
+        self.pinned.push(false);
+        self.nodes.len() - 1
+    }
+
+    pub fn add_edge(&mut self, from: usize, to: usize) {
+        self.edges.push((from, to));
+    }
+
+    pub fn pin(&mut self, node: usize, pinned: bool) {
+        self.pinned[node] = pinned;
+    }
+
+    /// Restores the initial temperature, so that a settled layout reacts to changes again
+    pub fn reheat(&mut self) {
+        self.temperature = self.settings.initial_temperature;
+    }
+
+    /// Moves every free node once and returns the largest displacement
+    pub fn step(&mut self) -> f32 {
+        let positions: Vec<Point2D> = self.nodes.iter().map(|n| n.point).collect();
+        if positions.is_empty() {
+            return 0.0;
+        }
+        let radii: Vec<f32> = self
+            .nodes
+            .iter()
+            .map(|n| {
+                n.bounding_box().map_or(0.0, |b| {
+                    Vector2D {
+                        x: b.shape.width,
+                        y: b.shape.height,
+                    }
+                    .length()
+                        / 2.0
+                })
+            })
+            .collect();
+        let k = self.settings.edge_length.max(MIN_DISTANCE);
+        let tree = QuadTree::new(&positions);
+        let mut forces: Vec<Vector2D> = (0..positions.len())
+            .map(|i| tree.repulsion(i, &positions, self.settings.theta, k * k))
+            .collect();
+        for &(a, b) in &self.edges {
+            if a == b {
+                continue;
+            }
+            let delta = positions[b] - positions[a];
+            let Some(direction) = delta.normalized() else {
+                continue;
+            };
+            let gap = (delta.length() - radii[a] - radii[b]).max(0.0);
+            let attraction = direction * (gap * gap / k);
+            forces[a] = forces[a].clone() + attraction.clone();
+            forces[b] = forces[b].clone() - attraction;
+        }
+        let mut max_displacement: f32 = 0.0;
+        for (i, force) in forces.into_iter().enumerate() {
+            if self.pinned[i] {
+                continue;
+            }
+            let force = force + (self.settings.center - positions[i]) * self.settings.gravity;
+            let Some(direction) = force.normalized() else {
+                continue;
+            };
+            let displacement = (force.length() * STEP_SIZE).min(self.temperature);
+            self.nodes[i].point = positions[i] + direction * displacement;
+            max_displacement = max_displacement.max(displacement);
+        }
+        self.temperature =
+            (self.temperature * self.settings.cooling).max(self.settings.min_temperature);
+        max_displacement
+    }
+
+    /// Steps until converged or `max_steps` is reached, returns the number of steps taken
+    pub fn run(&mut self, max_steps: usize) -> usize {
+        for steps in 1..=max_steps {
+            if self.step() < self.settings.tolerance {
+                return steps;
+            }
+        }
+        max_steps
+    }
+}
+
+/// Direction used to separate coincident nodes, fixed for every pair so layouts are
+/// reproducible. The two nodes of a pair are pushed in opposite directions.
+fn separation_direction(i: usize, j: usize) -> Vector2D {
+    let (low, high) = (i.min(j), i.max(j));
+    let direction =
+        Vector2D::by_angle(&Angle::degrees(((low * 7919 + high * 104729) % 360) as f32));
+    if i < j { direction } else { -direction }
+}
+
+/// Repulsion `k2 / d` pushing `p` away from `mass` bodies at `q`
+fn repulsion(p: &Point2D, q: &Point2D, mass: f32, k2: f32, i: usize, j: usize) -> Vector2D {
+    let delta = *p - *q;
+    let d = delta.length();
+    if d < MIN_DISTANCE {
+        return separation_direction(i, j) * (mass * k2 / MIN_DISTANCE);
+    }
+    delta * (mass * k2 / (d * d))
+}
+
+#[derive(Debug)]
+struct QuadCell {
+    min: Point2D,
+    size: f32,
+    mass: f32,
+    /// Sum of body positions, the center of mass once divided by `mass`
+    position_sum: Vector2D,
+    children: Option<[usize; 4]>,
+    bodies: Vec<usize>,
+}
+
+impl QuadCell {
+    fn new(min: Point2D, size: f32) -> QuadCell {
+        QuadCell {
+            min,
+            size,
+            mass: 0.0,
+            position_sum: Vector2D { x: 0.0, y: 0.0 },
+            children: None,
+            bodies: vec![],
+        }
+    }
+
+    fn quadrant(&self, p: &Point2D) -> usize {
+        let half = self.size / 2.0;
+        usize::from(p.x >= self.min.x + half) + 2 * usize::from(p.y >= self.min.y + half)
+    }
+
+    fn contains(&self, p: &Point2D) -> bool {
+        (self.min.x..=self.min.x + self.size).contains(&p.x)
+            && (self.min.y..=self.min.y + self.size).contains(&p.y)
+    }
+}
+
+struct QuadTree {
+    cells: Vec<QuadCell>,
+}
+
+impl QuadTree {
+    fn new(positions: &[Point2D]) -> QuadTree {
+        let bounds = bounding_box_of_points(positions).expect("positions are not empty");
+        let size = bounds
+            .shape
+            .width
+            .max(bounds.shape.height)
+            .max(MIN_DISTANCE);
+        let min = Point2D {
+            x: bounds.get_left(),
+            y: bounds.get_bottom(),
+        };
+        let mut tree = QuadTree {
+            cells: vec![QuadCell::new(min, size)],
+        };
+        for body in 0..positions.len() {
+            tree.insert(0, body, positions, 0);
+        }
+        tree
+    }
+
+    fn insert(&mut self, cell: usize, body: usize, positions: &[Point2D], depth: usize) {
+        let p = positions[body];
+        self.cells[cell].mass += 1.0;
+        self.cells[cell].position_sum =
+            self.cells[cell].position_sum.clone() + (p - Point2D::zero());
+        if let Some(children) = self.cells[cell].children {
+            let child = children[self.cells[cell].quadrant(&p)];
+            self.insert(child, body, positions, depth + 1);
+            return;
+        }
+        if self.cells[cell].bodies.is_empty() || depth == MAX_TREE_DEPTH {
+            self.cells[cell].bodies.push(body);
+            return;
+        }
+        let (min, half) = (self.cells[cell].min, self.cells[cell].size / 2.0);
+        let first = self.cells.len();
+        for k in 0..4 {
+            let corner = Point2D {
+                x: min.x + half * (k % 2) as f32,
+                y: min.y + half * (k / 2) as f32,
+            };
+            self.cells.push(QuadCell::new(corner, half));
+        }
+        self.cells[cell].children = Some([first, first + 1, first + 2, first + 3]);
+        let bodies = std::mem::take(&mut self.cells[cell].bodies);
+        for b in bodies.into_iter().chain([body]) {
+            let child = first + self.cells[cell].quadrant(&positions[b]);
+            self.insert(child, b, positions, depth + 1);
+        }
+    }
+
+    fn repulsion(&self, body: usize, positions: &[Point2D], theta: f32, k2: f32) -> Vector2D {
+        let mut force = Vector2D { x: 0.0, y: 0.0 };
+        let mut stack = vec![0];
+        let p = positions[body];
+        while let Some(cell) = stack.pop() {
+            let cell_ref = &self.cells[cell];
+            if cell_ref.mass == 0.0 {
+                continue;
+            }
+            let Some(children) = cell_ref.children else {
+                for &other in &cell_ref.bodies {
+                    if other != body {
+                        force = force + repulsion(&p, &positions[other], 1.0, k2, body, other);
+                    }
+                }
+                continue;
+            };
+            let center = Point2D::zero() + cell_ref.position_sum.clone() / cell_ref.mass;
+            let d = (p - center).length();
+            if !cell_ref.contains(&p) && cell_ref.size < theta * d {
+                force = force + repulsion(&p, &center, cell_ref.mass, k2, body, cell);
+            } else {
+                stack.extend(children);
+            }
+        }
+        force
+    }
+}
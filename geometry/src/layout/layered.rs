@@ -0,0 +1,350 @@
+use super::overlap::{Separation, satisfy};
+use crate::{
+    point::Point2D,
+    shapes::{
+        affine::{EmbedInAffine2D, TypedAffine2D},
+        polyline::Polyline,
+        rectangle::Rectangle,
+    },
+};
+
+#[derive(Debug, Clone)]
+pub struct LayeredLayoutSettings {
+    /// Vertical gap between the lowest node of a layer and the highest of the next one
+    pub layer_spacing: f32,
+    /// Horizontal gap between neighbouring nodes of a layer
+    pub node_spacing: f32,
+    /// Barycenter sweeps used to reduce edge crossings
+    pub crossing_sweeps: usize,
+    /// Passes pulling nodes towards their neighbours in adjacent layers
+    pub alignment_passes: usize,
+}
+
+impl Default for LayeredLayoutSettings {
+    fn default() -> Self {
+        LayeredLayoutSettings {
+            layer_spacing: 60.0,
+            node_spacing: 30.0,
+            crossing_sweeps: 8,
+            alignment_passes: 8,
+        }
+    }
+}
+
+/// Result of a layered layout, flowing from the top down
+#[derive(Debug, Clone)]
+pub struct LayeredLayout {
+    pub nodes: Vec<TypedAffine2D<Rectangle>>,
+    /// Layer of every node, 0 is the top layer
+    pub layers: Vec<usize>,
+    /// Route of every edge from its source to its target, empty for self loops
+    pub routes: Vec<Polyline>,
+}
+
+/// Hierarchical layout of a directed graph (Sugiyama): cycles are broken by reversing
+/// edges, nodes are put on layers by longest path, layers are reordered by barycenters
+/// to reduce crossings and nodes are then aligned with their neighbours
+pub fn layered_layout(
+    sizes: &[Rectangle],
+    edges: &[(usize, usize)],
+    settings: &LayeredLayoutSettings,
+) -> LayeredLayout {
+    let n = sizes.len();
+    let reversed = break_cycles(n, edges);
+    let oriented: Vec<(usize, usize)> = edges
+        .iter()
+        .zip(&reversed)
+        .map(|(&(a, b), &r)| if r { (b, a) } else { (a, b) })
+        .collect();
+    let mut layer_of = assign_layers(n, &oriented);
+
+    // edges spanning several layers go through a dummy node on every layer in between
+    let mut chains: Vec<Vec<usize>> = Vec::with_capacity(edges.len());
+    for &(a, b) in &oriented {
+        if a == b {
+            chains.push(vec![]);
+            continue;
+        }
+        let mut chain = vec![a];
+        for layer in layer_of[a] + 1..layer_of[b] {
+            chain.push(layer_of.len());
+            layer_of.push(layer);
+        }
+        chain.push(b);
+        chains.push(chain);
+    }
+    let total = layer_of.len();
+    let mut up: Vec<Vec<usize>> = vec![vec![]; total];
+    let mut down: Vec<Vec<usize>> = vec![vec![]; total];
+    for chain in &chains {
+        for w in chain.windows(2) {
+            down[w[0]].push(w[1]);
+            up[w[1]].push(w[0]);
+        }
+    }
+    let layer_count = layer_of.iter().max().map_or(0, |l| l + 1);
+    let mut order: Vec<Vec<usize>> = vec![vec![]; layer_count];
+    for (v, &layer) in layer_of.iter().enumerate() {
+        order[layer].push(v);
+    }
+    reduce_crossings(&mut order, &up, &down, settings.crossing_sweeps);
+
+    let widths: Vec<f32> = (0..total)
+        .map(|v| sizes.get(v).map_or(0.0, |s| s.width))
+        .collect();
+    let xs = assign_x(&order, &up, &down, &widths, n, settings);
+    let mut layer_centers = Vec::with_capacity(layer_count);
+    let mut top = 0.0;
+    for layer in &order {
+        let height = layer
+            .iter()
+            .filter_map(|&v| sizes.get(v))
+            .map(|s| s.height)
+            .fold(0.0, f32::max);
+        layer_centers.push(top - height / 2.0);
+        top -= height + settings.layer_spacing;
+    }
+
+    let nodes: Vec<TypedAffine2D<Rectangle>> = sizes
+        .iter()
+        .enumerate()
+        .map(|(v, size)| {
+            size.clone().embed_affine(&Point2D {
+                x: xs[v],
+                y: layer_centers[layer_of[v]],
+            })
+        })
+        .collect();
+    let routes = chains
+        .iter()
+        .zip(&reversed)
+        .map(|(chain, &r)| {
+            let (Some(&first), Some(&last)) = (chain.first(), chain.last()) else {
+                return Polyline::new(vec![]);
+            };
+            let mut points = vec![Point2D {
+                x: xs[first],
+                y: nodes[first].get_bottom(),
+            }];
+            points.extend(chain[1..chain.len() - 1].iter().map(|&d| Point2D {
+                x: xs[d],
+                y: layer_centers[layer_of[d]],
+            }));
+            points.push(Point2D {
+                x: xs[last],
+                y: nodes[last].get_top(),
+            });
+            if r {
+                points.reverse();
+            }
+            Polyline::new(points)
+        })
+        .collect();
+    LayeredLayout {
+        layers: layer_of[..n].to_vec(),
+        nodes,
+        routes,
+    }
+}
+
+/// Edges to reverse so that the graph has no cycles, found by depth first search
+fn break_cycles(n: usize, edges: &[(usize, usize)]) -> Vec<bool> {
+    let mut outgoing: Vec<Vec<(usize, usize)>> = vec![vec![]; n];
+    for (e, &(a, b)) in edges.iter().enumerate() {
+        if a != b {
+            outgoing[a].push((e, b));
+        }
+    }
+    let mut reversed = vec![false; edges.len()];
+    // 0 not visited, 1 on the search path, 2 finished
+    let mut state = vec![0u8; n];
+    for root in 0..n {
+        if state[root] != 0 {
+            continue;
+        }
+        state[root] = 1;
+        let mut stack = vec![(root, 0)];
+        while let Some((v, next)) = stack.last_mut() {
+            let v = *v;
+            let Some(&(e, w)) = outgoing[v].get(*next) else {
+                state[v] = 2;
+                stack.pop();
+                continue;
+            };
+            *next += 1;
+            match state[w] {
+                0 => {
+                    state[w] = 1;
+                    stack.push((w, 0));
+                }
+                1 => reversed[e] = true,
+                _ => {}
+            }
+        }
+    }
+    reversed
+}
+
+/// Longest path layering of an acyclic graph, sources are on layer 0
+fn assign_layers(n: usize, edges: &[(usize, usize)]) -> Vec<usize> {
+    let mut outgoing: Vec<Vec<usize>> = vec![vec![]; n];
+    let mut in_degree = vec![0; n];
+    for &(a, b) in edges {
+        if a != b {
+            outgoing[a].push(b);
+            in_degree[b] += 1;
+        }
+    }
+    let mut layers = vec![0; n];
+    let mut ready: Vec<usize> = (0..n).filter(|&v| in_degree[v] == 0).collect();
+    while let Some(v) = ready.pop() {
+        for &w in &outgoing[v] {
+            layers[w] = layers[w].max(layers[v] + 1);
+            in_degree[w] -= 1;
+            if in_degree[w] == 0 {
+                ready.push(w);
+            }
+        }
+    }
+    layers
+}
+
+fn positions(order: &[Vec<usize>], total: usize) -> Vec<usize> {
+    let mut positions = vec![0; total];
+    for layer in order {
+        for (i, &v) in layer.iter().enumerate() {
+            positions[v] = i;
+        }
+    }
+    positions
+}
+
+fn count_crossings(order: &[Vec<usize>], down: &[Vec<usize>]) -> usize {
+    let positions = positions(order, down.len());
+    order
+        .iter()
+        .map(|layer| {
+            let ends: Vec<(usize, usize)> = layer
+                .iter()
+                .flat_map(|&v| down[v].iter().map(move |&w| (v, w)))
+                .map(|(v, w)| (positions[v], positions[w]))
+                .collect();
+            let mut crossings = 0;
+            for (i, a) in ends.iter().enumerate() {
+                for b in &ends[i + 1..] {
+                    if (a.0 < b.0 && a.1 > b.1) || (a.0 > b.0 && a.1 < b.1) {
+                        crossings += 1;
+                    }
+                }
+            }
+            crossings
+        })
+        .sum()
+}
+
+/// Alternating down and up sweeps sorting every layer by the mean position of the
+/// neighbours in the previous layer, the order with the fewest crossings is kept
+fn reduce_crossings(
+    order: &mut Vec<Vec<usize>>,
+    up: &[Vec<usize>],
+    down: &[Vec<usize>],
+    sweeps: usize,
+) {
+    let mut best = order.clone();
+    let mut best_crossings = count_crossings(order, down);
+    for sweep in 0..sweeps {
+        let downwards = sweep % 2 == 0;
+        let layers: Vec<usize> = if downwards {
+            (1..order.len()).collect()
+        } else {
+            (0..order.len().saturating_sub(1)).rev().collect()
+        };
+        for layer in layers {
+            let positions = positions(order, up.len());
+            let neighbours = if downwards { up } else { down };
+            let barycenter = |v: usize| {
+                if neighbours[v].is_empty() {
+                    positions[v] as f32
+                } else {
+                    neighbours[v]
+                        .iter()
+                        .map(|&w| positions[w] as f32)
+                        .sum::<f32>()
+                        / neighbours[v].len() as f32
+                }
+            };
+            order[layer].sort_by(|&a, &b| barycenter(a).total_cmp(&barycenter(b)));
+        }
+        let crossings = count_crossings(order, down);
+        if crossings < best_crossings {
+            best_crossings = crossings;
+            best = order.clone();
+        }
+    }
+    *order = best;
+}
+
+/// Horizontal centers, every layer is packed first then nodes are repeatedly moved
+/// towards the mean of their neighbours while keeping the layer order and spacing
+fn assign_x(
+    order: &[Vec<usize>],
+    up: &[Vec<usize>],
+    down: &[Vec<usize>],
+    widths: &[f32],
+    real_nodes: usize,
+    settings: &LayeredLayoutSettings,
+) -> Vec<f32> {
+    let gap = |a: usize, b: usize| {
+        let spacing = if a < real_nodes && b < real_nodes {
+            settings.node_spacing
+        } else {
+            settings.node_spacing / 2.0
+        };
+        (widths[a] + widths[b]) / 2.0 + spacing
+    };
+    let mut xs = vec![0.0; widths.len()];
+    for layer in order {
+        let mut x = 0.0;
+        for (i, &v) in layer.iter().enumerate() {
+            if i > 0 {
+                x += gap(layer[i - 1], v);
+            }
+            xs[v] = x;
+        }
+        let shift = x / 2.0;
+        for &v in layer {
+            xs[v] -= shift;
+        }
+    }
+    for pass in 0..settings.alignment_passes {
+        let neighbours = if pass % 2 == 0 { up } else { down };
+        for layer in order {
+            let desired: Vec<f32> = layer
+                .iter()
+                .map(|&v| {
+                    if neighbours[v].is_empty() {
+                        xs[v]
+                    } else {
+                        neighbours[v].iter().map(|&w| xs[w]).sum::<f32>()
+                            / neighbours[v].len() as f32
+                    }
+                })
+                .collect();
+            let local_order: Vec<usize> = (0..layer.len()).collect();
+            let separations: Vec<Separation> = (1..layer.len())
+                .map(|i| Separation {
+                    left: i - 1,
+                    right: i,
+                    gap: gap(layer[i - 1], layer[i]),
+                })
+                .collect();
+            for (&v, x) in layer
+                .iter()
+                .zip(satisfy(&desired, &local_order, &separations))
+            {
+                xs[v] = x;
+            }
+        }
+    }
+    xs
+}
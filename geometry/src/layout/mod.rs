@@ -0,0 +1,5 @@
+pub mod arrange;
+pub mod force;
+pub mod layered;
+pub mod overlap;
+pub mod tree;
@@ -0,0 +1,177 @@
+use crate::{
+    collisions::collision_predicate_functions::check_collision_rect_rect,
+    comparators::is_zero,
+    shapes::{affine::TypedAffine2D, rectangle::Rectangle},
+};
+
+/// Violations smaller than this are left alone by the separation solver
+const VIOLATION_TOLERANCE: f32 = 0.00001;
+
+/// Lower bound `positions[right] - positions[left] >= gap`
+pub(super) struct Separation {
+    pub left: usize,
+    pub right: usize,
+    pub gap: f32,
+}
+
+/// Variables moved together, each at a fixed offset from the block position
+struct Block {
+    vars: Vec<usize>,
+    position: f32,
+    /// Sum of desired positions minus offsets of all variables
+    weighted: f32,
+}
+
+/// Positions closest to `desired` in the least squares sense that satisfy all separations,
+/// separations must go from earlier to later variables of `order`. Blocks of variables are
+/// merged along the most violated separation until none is left (VPSC satisfy step).
+pub(super) fn satisfy(desired: &[f32], order: &[usize], separations: &[Separation]) -> Vec<f32> {
+    let n = desired.len();
+    let mut incoming: Vec<Vec<usize>> = vec![vec![]; n];
+    for (i, s) in separations.iter().enumerate() {
+        incoming[s.right].push(i);
+    }
+    let mut block_of = vec![0; n];
+    let mut offset = vec![0.0; n];
+    let mut blocks: Vec<Block> = Vec::with_capacity(n);
+    for &v in order {
+        let b = blocks.len();
+        block_of[v] = b;
+        blocks.push(Block {
+            vars: vec![v],
+            position: desired[v],
+            weighted: desired[v],
+        });
+        loop {
+            let position = |u: usize| blocks[block_of[u]].position + offset[u];
+            let worst = blocks[b]
+                .vars
+                .iter()
+                .flat_map(|&r| incoming[r].iter().map(|&s| &separations[s]))
+                .filter(|s| block_of[s.left] != b)
+                .map(|s| (s, position(s.left) + s.gap - position(s.right)))
+                .max_by(|(_, v1), (_, v2)| v1.total_cmp(v2));
+            let Some((s, _)) = worst.filter(|(_, violation)| *violation > VIOLATION_TOLERANCE)
+            else {
+                break;
+            };
+            let shift = offset[s.right] - s.gap - offset[s.left];
+            let merged = std::mem::take(&mut blocks[block_of[s.left]].vars);
+            for u in merged {
+                offset[u] += shift;
+                block_of[u] = b;
+                blocks[b].weighted += desired[u] - offset[u];
+                blocks[b].vars.push(u);
+            }
+            blocks[b].position = blocks[b].weighted / blocks[b].vars.len() as f32;
+        }
+    }
+    let mut positions: Vec<f32> = (0..n)
+        .map(|v| blocks[block_of[v]].position + offset[v])
+        .collect();
+    // merging may push an earlier block past a separation it had slack on, a forward
+    // sweep restores those
+    for &v in order {
+        for &s in &incoming[v] {
+            let s = &separations[s];
+            positions[v] = positions[v].max(positions[s.left] + s.gap);
+        }
+    }
+    positions
+}
+
+fn center(node: &TypedAffine2D<Rectangle>, horizontal: bool) -> f32 {
+    if horizontal {
+        node.point.x
+    } else {
+        node.point.y
+    }
+}
+
+fn size(node: &TypedAffine2D<Rectangle>, horizontal: bool) -> f32 {
+    if horizontal {
+        node.shape.width
+    } else {
+        node.shape.height
+    }
+}
+
+fn overlap(
+    a: &TypedAffine2D<Rectangle>,
+    b: &TypedAffine2D<Rectangle>,
+    horizontal: bool,
+    gap: f32,
+) -> f32 {
+    (size(a, horizontal) + size(b, horizontal)) / 2.0 + gap
+        - (center(a, horizontal) - center(b, horizontal)).abs()
+}
+
+/// Moves rectangles along one axis as little as possible so that every pair accepted by
+/// `needs_separation` ends up `gap` apart, without changing their order along that axis
+fn separate(
+    nodes: &mut [TypedAffine2D<Rectangle>],
+    horizontal: bool,
+    gap: f32,
+    needs_separation: impl Fn(&TypedAffine2D<Rectangle>, &TypedAffine2D<Rectangle>) -> bool,
+) {
+    let mut order: Vec<usize> = (0..nodes.len()).collect();
+    order.sort_by(|&i, &j| {
+        center(&nodes[i], horizontal)
+            .total_cmp(&center(&nodes[j], horizontal))
+            .then(i.cmp(&j))
+    });
+    let mut separations: Vec<Separation> = order
+        .windows(2)
+        .map(|w| Separation {
+            left: w[0],
+            right: w[1],
+            gap: 0.0,
+        })
+        .collect();
+    for (k, &left) in order.iter().enumerate() {
+        for &right in &order[k + 1..] {
+            if needs_separation(&nodes[left], &nodes[right]) {
+                separations.push(Separation {
+                    left,
+                    right,
+                    gap: (size(&nodes[left], horizontal) + size(&nodes[right], horizontal)) / 2.0
+                        + gap,
+                });
+            }
+        }
+    }
+    let desired: Vec<f32> = nodes.iter().map(|n| center(n, horizontal)).collect();
+    let positions = satisfy(&desired, &order, &separations);
+    for (node, position) in nodes.iter_mut().zip(positions) {
+        if horizontal {
+            node.point.x = position;
+        } else {
+            node.point.y = position;
+        }
+    }
+}
+
+/// Moves rectangles so that no two of them are closer than `gap`, keeping their left to
+/// right and bottom to top order. Overlapping pairs are pushed apart horizontally or
+/// vertically, whichever needs the smaller move, then the total squared displacement is
+/// minimized along each axis in turn.
+pub fn remove_overlaps(
+    nodes: &[TypedAffine2D<Rectangle>],
+    gap: f32,
+) -> Vec<TypedAffine2D<Rectangle>> {
+    let mut nodes = nodes.to_vec();
+    let gap = gap.max(0.0);
+    let shares_extent = |a: &TypedAffine2D<Rectangle>, b: &TypedAffine2D<Rectangle>, horizontal| {
+        let o = overlap(a, b, horizontal, gap);
+        o > 0.0 && !is_zero(o)
+    };
+    // pairs side by side stay apart, overlapping pairs cheaper to move sideways are split
+    separate(&mut nodes, true, gap, |a, b| {
+        let overlapping = check_collision_rect_rect(&a.inflated(gap / 2.0), &b.inflated(gap / 2.0));
+        shares_extent(a, b, false)
+            && (!overlapping || overlap(a, b, true, gap) <= overlap(a, b, false, gap))
+    });
+    // any pair still sharing a column is stacked, which leaves no overlap at all
+    separate(&mut nodes, false, gap, |a, b| shares_extent(a, b, true));
+    nodes
+}
@@ -0,0 +1,324 @@
+use std::collections::VecDeque;
+
+use crate::{
+    point::Point2D,
+    shapes::{
+        affine::{EmbedInAffine2D, TypedAffine2D},
+        rectangle::Rectangle,
+    },
+};
+
+/// Direction from a parent to its children
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TreeOrientation {
+    /// Root on top, siblings from left to right
+    TopDown,
+    /// Root on the left, siblings from top to bottom
+    LeftRight,
+}
+
+#[derive(Debug, Clone)]
+pub struct TreeLayoutSettings {
+    /// Gap between neighbouring subtrees of the same level
+    pub sibling_spacing: f32,
+    /// Gap between the nodes of a level and the nodes of the next one
+    pub level_spacing: f32,
+    pub orientation: TreeOrientation,
+}
+
+impl Default for TreeLayoutSettings {
+    fn default() -> Self {
+        TreeLayoutSettings {
+            sibling_spacing: 20.0,
+            level_spacing: 40.0,
+            orientation: TreeOrientation::TopDown,
+        }
+    }
+}
+
+/// Tidy tree layout (Reingold-Tilford with Walker's apportioning, in linear time):
+/// subtrees are pushed together as close as their contours allow, smaller subtrees
+/// between two pushed apart are spread evenly and parents are centered over their
+/// first and last child. Edges to
+/// nodes already reached from `root` (back-links) are ignored, nodes that cannot be
+/// reached are laid out as further trees next to the main one.
+pub fn tree_layout(
+    sizes: &[Rectangle],
+    edges: &[(usize, usize)],
+    root: usize,
+    settings: &TreeLayoutSettings,
+) -> Vec<TypedAffine2D<Rectangle>> {
+    let n = sizes.len();
+    if n == 0 {
+        return vec![];
+    }
+    let mut outgoing: Vec<Vec<usize>> = vec![vec![]; n];
+    for &(a, b) in edges {
+        outgoing[a].push(b);
+    }
+    // spanning forest found breadth first, the trees hang below a virtual node `n`
+    let mut children: Vec<Vec<usize>> = vec![vec![]; n + 1];
+    let mut depth = vec![0; n];
+    let mut visited = vec![false; n];
+    for tree_root in [root].into_iter().chain(0..n) {
+        if visited[tree_root] {
+            continue;
+        }
+        visited[tree_root] = true;
+        children[n].push(tree_root);
+        let mut queue = VecDeque::from([tree_root]);
+        while let Some(v) = queue.pop_front() {
+            for &w in &outgoing[v] {
+                if !visited[w] {
+                    visited[w] = true;
+                    depth[w] = depth[v] + 1;
+                    children[v].push(w);
+                    queue.push_back(w);
+                }
+            }
+        }
+    }
+
+    let (breadths, thicknesses): (Vec<f32>, Vec<f32>) = sizes
+        .iter()
+        .map(|s| match settings.orientation {
+            TreeOrientation::TopDown => (s.width, s.height),
+            TreeOrientation::LeftRight => (s.height, s.width),
+        })
+        .chain([(0.0, 0.0)])
+        .unzip();
+    let mut tidy = TidyTree::new(children, breadths, settings.sibling_spacing);
+    tidy.first_walk(n);
+    let breadth_positions = tidy.second_walk(n);
+
+    // levels are as thick as their thickest node, nodes are aligned to the level start
+    let levels = depth.iter().max().map_or(0, |d| d + 1);
+    let mut level_thickness = vec![0.0f32; levels];
+    for v in 0..n {
+        level_thickness[depth[v]] = level_thickness[depth[v]].max(thicknesses[v]);
+    }
+    let mut level_starts = Vec::with_capacity(levels);
+    let mut start = 0.0;
+    for thickness in level_thickness {
+        level_starts.push(start);
+        start += thickness + settings.level_spacing;
+    }
+
+    // the main tree root is placed at the origin
+    let shift = breadth_positions[root];
+    (0..n)
+        .map(|v| {
+            let along = breadth_positions[v] - shift;
+            let across = level_starts[depth[v]] + thicknesses[v] / 2.0;
+            let center = match settings.orientation {
+                TreeOrientation::TopDown => Point2D {
+                    x: along,
+                    y: -across,
+                },
+                TreeOrientation::LeftRight => Point2D {
+                    x: across,
+                    y: -along,
+                },
+            };
+            sizes[v].clone().embed_affine(&center)
+        })
+        .collect()
+}
+
+/// State of the Buchheim-Walker layout. Contours are followed through threads instead
+/// of being copied, and every walk runs with an explicit stack so deep trees do not
+/// overflow.
+struct TidyTree {
+    children: Vec<Vec<usize>>,
+    breadths: Vec<f32>,
+    spacing: f32,
+    parent: Vec<Option<usize>>,
+    /// Position among the siblings
+    number: Vec<usize>,
+    /// Position relative to the left sibling, or to the children for first children
+    prelim: Vec<f32>,
+    /// Shift of the whole subtree below a node, applied in the second walk
+    modifier: Vec<f32>,
+    /// Next node of a contour for nodes without children
+    thread: Vec<Option<usize>>,
+    ancestor: Vec<usize>,
+    shift: Vec<f32>,
+    change: Vec<f32>,
+    /// Middle of the first and last child, relative to the node
+    midpoint: Vec<f32>,
+}
+
+impl TidyTree {
+    fn new(children: Vec<Vec<usize>>, breadths: Vec<f32>, spacing: f32) -> TidyTree {
+        let n = children.len();
+        let mut parent = vec![None; n];
+        let mut number = vec![0; n];
+        for (v, vs) in children.iter().enumerate() {
+            for (i, &c) in vs.iter().enumerate() {
+                parent[c] = Some(v);
+                number[c] = i;
+            }
+        }
+        TidyTree {
+            children,
+            breadths,
+            spacing,
+            parent,
+            number,
+            prelim: vec![0.0; n],
+            modifier: vec![0.0; n],
+            thread: vec![None; n],
+            ancestor: (0..n).collect(),
+            shift: vec![0.0; n],
+            change: vec![0.0; n],
+            midpoint: vec![0.0; n],
+        }
+    }
+
+    fn left_sibling(&self, v: usize) -> Option<usize> {
+        let p = self.parent[v]?;
+        self.number[v].checked_sub(1).map(|i| self.children[p][i])
+    }
+
+    fn leftmost_sibling(&self, v: usize) -> usize {
+        self.parent[v].map_or(v, |p| self.children[p][0])
+    }
+
+    fn next_left(&self, v: usize) -> Option<usize> {
+        self.children[v].first().copied().or(self.thread[v])
+    }
+
+    fn next_right(&self, v: usize) -> Option<usize> {
+        self.children[v].last().copied().or(self.thread[v])
+    }
+
+    fn distance(&self, a: usize, b: usize) -> f32 {
+        (self.breadths[a] + self.breadths[b]) / 2.0 + self.spacing
+    }
+
+    /// Computes `prelim` and `modifier` bottom up, children are handled by their parent
+    /// once all of them are laid out
+    fn first_walk(&mut self, root: usize) {
+        let mut stack = vec![(root, false)];
+        while let Some((v, expanded)) = stack.pop() {
+            if !expanded {
+                stack.push((v, true));
+                stack.extend(self.children[v].iter().map(|&c| (c, false)));
+                continue;
+            }
+            if self.children[v].is_empty() {
+                continue;
+            }
+            let mut default_ancestor = self.children[v][0];
+            for i in 0..self.children[v].len() {
+                let c = self.children[v][i];
+                self.place(c);
+                default_ancestor = self.apportion(c, default_ancestor);
+            }
+            self.execute_shifts(v);
+            let (first, last) = (
+                self.children[v][0],
+                self.children[v][self.children[v].len() - 1],
+            );
+            self.midpoint[v] = (self.prelim[first] + self.prelim[last]) / 2.0;
+        }
+        self.place(root);
+    }
+
+    fn place(&mut self, v: usize) {
+        match self.left_sibling(v) {
+            Some(w) => {
+                self.prelim[v] = self.prelim[w] + self.distance(w, v);
+                if !self.children[v].is_empty() {
+                    self.modifier[v] = self.prelim[v] - self.midpoint[v];
+                }
+            }
+            None => self.prelim[v] = self.midpoint[v],
+        }
+    }
+
+    /// Pushes the subtree of `v` clear of its left siblings' subtrees, spreading the
+    /// shift over the siblings in between
+    fn apportion(&mut self, v: usize, default_ancestor: usize) -> usize {
+        let Some(w) = self.left_sibling(v) else {
+            return default_ancestor;
+        };
+        let mut default_ancestor = default_ancestor;
+        // inner and outer contours on the right (p) and the left (m) side
+        let (mut vip, mut vop) = (v, v);
+        let (mut vim, mut vom) = (w, self.leftmost_sibling(v));
+        let (mut sip, mut sop) = (self.modifier[vip], self.modifier[vop]);
+        let (mut sim, mut som) = (self.modifier[vim], self.modifier[vom]);
+        while let (Some(next_im), Some(next_ip)) = (self.next_right(vim), self.next_left(vip)) {
+            vim = next_im;
+            vip = next_ip;
+            vom = self
+                .next_left(vom)
+                .expect("outer contour is as deep as the inner one");
+            vop = self
+                .next_right(vop)
+                .expect("outer contour is as deep as the inner one");
+            self.ancestor[vop] = v;
+            let shift =
+                (self.prelim[vim] + sim) - (self.prelim[vip] + sip) + self.distance(vim, vip);
+            if shift > 0.0 {
+                let a = if self.parent[self.ancestor[vim]] == self.parent[v] {
+                    self.ancestor[vim]
+                } else {
+                    default_ancestor
+                };
+                self.move_subtree(a, v, shift);
+                sip += shift;
+                sop += shift;
+            }
+            sim += self.modifier[vim];
+            sip += self.modifier[vip];
+            som += self.modifier[vom];
+            sop += self.modifier[vop];
+        }
+        if let (Some(next), None) = (self.next_right(vim), self.next_right(vop)) {
+            self.thread[vop] = Some(next);
+            self.modifier[vop] += sim - sop;
+        }
+        if let (Some(next), None) = (self.next_left(vip), self.next_left(vom)) {
+            self.thread[vom] = Some(next);
+            self.modifier[vom] += sip - som;
+            default_ancestor = v;
+        }
+        default_ancestor
+    }
+
+    /// Moves the subtree of `right` by `shift` and records the shares of the siblings
+    /// between `left` and `right` for `execute_shifts`
+    fn move_subtree(&mut self, left: usize, right: usize, shift: f32) {
+        let share = shift / (self.number[right] - self.number[left]) as f32;
+        self.change[right] -= share;
+        self.shift[right] += shift;
+        self.change[left] += share;
+        self.prelim[right] += shift;
+        self.modifier[right] += shift;
+    }
+
+    fn execute_shifts(&mut self, v: usize) {
+        let (mut shift, mut change) = (0.0, 0.0);
+        for &c in self.children[v].iter().rev() {
+            self.prelim[c] += shift;
+            self.modifier[c] += shift;
+            change += self.change[c];
+            shift += self.shift[c] + change;
+        }
+    }
+
+    /// Final positions along the levels, summing the modifiers of all ancestors
+    fn second_walk(&self, root: usize) -> Vec<f32> {
+        let mut positions = vec![0.0; self.children.len()];
+        let mut stack = vec![(root, 0.0)];
+        while let Some((v, modifiers)) = stack.pop() {
+            positions[v] = self.prelim[v] + modifiers;
+            for &c in &self.children[v] {
+                stack.push((c, modifiers + self.modifier[v]));
+            }
+        }
+        positions
+    }
+}
@@ -1,7 +1,23 @@
 pub mod shapes;
 pub mod angle;
+pub mod bounding;
+pub mod clipping;
 pub mod collisions;
+pub mod containment;
+pub mod debug_scene;
+pub mod delaunay;
+pub mod layout;
+pub mod measure;
+pub mod offset;
 pub mod point;
+pub mod routing;
+pub mod snapping;
+pub mod svg;
+pub mod sweep;
+pub mod triangulation;
 pub mod vector;
+pub mod viewport;
 
 mod comparators;
+#[cfg(feature = "nalgebra")]
+mod nalgebra_interop;
@@ -0,0 +1,284 @@
+use std::f32::consts::PI;
+
+use crate::{
+    comparators::is_zero,
+    point::{Point2D, dist},
+    shapes::{
+        Shape2D,
+        affine::{Affine2D, EmbedInAffine2D, TypedAffine2D},
+        circle::Circle,
+        line::Line2D,
+        polygon::Polygon,
+        rectangle::Rectangle,
+        rounded_rectangle::RoundedRectangle,
+        segment::Segment2D,
+    },
+};
+
+/// Second moment of area about axes parallel to x and y passing through the centroid
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SecondMoment {
+    /// Integral of y^2 over the area
+    pub ixx: f32,
+    /// Integral of x^2 over the area
+    pub iyy: f32,
+    /// Integral of xy over the area
+    pub ixy: f32,
+}
+
+impl SecondMoment {
+    pub const fn zero() -> SecondMoment {
+        SecondMoment {
+            ixx: 0.0,
+            iyy: 0.0,
+            ixy: 0.0,
+        }
+    }
+
+    pub fn polar(&self) -> f32 {
+        self.ixx + self.iyy
+    }
+}
+
+pub trait Measure {
+    fn area(&self) -> f32;
+    /// Length of the boundary, for segments this is their length
+    fn perimeter(&self) -> f32;
+    fn centroid(&self) -> Point2D;
+    fn second_moment(&self) -> SecondMoment;
+}
+
+impl Measure for TypedAffine2D<Rectangle> {
+    fn area(&self) -> f32 {
+        self.shape.width * self.shape.height
+    }
+
+    fn perimeter(&self) -> f32 {
+        2.0 * (self.shape.width + self.shape.height)
+    }
+
+    fn centroid(&self) -> Point2D {
+        self.point
+    }
+
+    fn second_moment(&self) -> SecondMoment {
+        let (w, h) = (self.shape.width, self.shape.height);
+        SecondMoment {
+            ixx: w * h.powi(3) / 12.0,
+            iyy: h * w.powi(3) / 12.0,
+            ixy: 0.0,
+        }
+    }
+}
+
+impl Measure for TypedAffine2D<RoundedRectangle> {
+    fn area(&self) -> f32 {
+        let r = self.get_radius();
+        self.shape.width * self.shape.height - (4.0 - PI) * r * r
+    }
+
+    fn perimeter(&self) -> f32 {
+        let r = self.get_radius();
+        2.0 * (self.shape.width + self.shape.height) - (8.0 - 2.0 * PI) * r
+    }
+
+    fn centroid(&self) -> Point2D {
+        self.point
+    }
+
+    fn second_moment(&self) -> SecondMoment {
+        let r = self.get_radius();
+        let (w, h) = (self.shape.width, self.shape.height);
+        // full length band, two strips closing it and four quarter discs at the corners
+        let about_x = |w: f32, h: f32| {
+            let corner = h / 2.0 - r;
+            let band = w * (h - 2.0 * r).powi(3) / 12.0;
+            let strips = 2.0 * (w - 2.0 * r) * r * (r * r / 12.0 + (corner + r / 2.0).powi(2));
+            let discs = PI * r.powi(4) / 4.0
+                + 8.0 * corner * r.powi(3) / 3.0
+                + PI * corner * corner * r * r;
+            band + strips + discs
+        };
+        SecondMoment {
+            ixx: about_x(w, h),
+            iyy: about_x(h, w),
+            ixy: 0.0,
+        }
+    }
+}
+
+impl Measure for TypedAffine2D<Circle> {
+    fn area(&self) -> f32 {
+        PI * self.shape.radius.powi(2)
+    }
+
+    fn perimeter(&self) -> f32 {
+        2.0 * PI * self.shape.radius
+    }
+
+    fn centroid(&self) -> Point2D {
+        self.point
+    }
+
+    fn second_moment(&self) -> SecondMoment {
+        let i = PI * self.shape.radius.powi(4) / 4.0;
+        SecondMoment {
+            ixx: i,
+            iyy: i,
+            ixy: 0.0,
+        }
+    }
+}
+
+impl Measure for TypedAffine2D<Segment2D> {
+    fn area(&self) -> f32 {
+        0.0
+    }
+
+    fn perimeter(&self) -> f32 {
+        self.shape.length
+    }
+
+    fn centroid(&self) -> Point2D {
+        self.point
+    }
+
+    fn second_moment(&self) -> SecondMoment {
+        SecondMoment::zero()
+    }
+}
+
+impl Measure for TypedAffine2D<Line2D> {
+    fn area(&self) -> f32 {
+        0.0
+    }
+
+    fn perimeter(&self) -> f32 {
+        f32::INFINITY
+    }
+
+    fn centroid(&self) -> Point2D {
+        self.point
+    }
+
+    fn second_moment(&self) -> SecondMoment {
+        SecondMoment::zero()
+    }
+}
+
+impl Measure for TypedAffine2D<Polygon> {
+    fn area(&self) -> f32 {
+        self.shape.signed_double_area().abs() / 2.0
+    }
+
+    fn perimeter(&self) -> f32 {
+        self.shape.get_edges().map(|(a, b)| dist(&a, &b)).sum()
+    }
+
+    fn centroid(&self) -> Point2D {
+        let double_area = self.shape.signed_double_area();
+        let vertices = &self.shape.vertices;
+        if is_zero(double_area.abs()) {
+            // degenerate polygon, fall back to the average of vertices
+            if vertices.is_empty() {
+                return self.point;
+            }
+            let n = vertices.len() as f32;
+            let (sx, sy) = vertices
+                .iter()
+                .fold((0.0, 0.0), |(sx, sy), v| (sx + v.x, sy + v.y));
+            return Point2D {
+                x: self.point.x + sx / n,
+                y: self.point.y + sy / n,
+            };
+        }
+        let (cx, cy) = self.shape.get_edges().fold((0.0, 0.0), |(cx, cy), (a, b)| {
+            let cross = a.x * b.y - b.x * a.y;
+            (cx + (a.x + b.x) * cross, cy + (a.y + b.y) * cross)
+        });
+        Point2D {
+            x: self.point.x + cx / (3.0 * double_area),
+            y: self.point.y + cy / (3.0 * double_area),
+        }
+    }
+
+    fn second_moment(&self) -> SecondMoment {
+        let double_area = self.shape.signed_double_area();
+        if is_zero(double_area.abs()) {
+            return SecondMoment::zero();
+        }
+        // moments about the local origin, then shifted to the centroid
+        let (ixx, iyy, ixy) =
+            self.shape
+                .get_edges()
+                .fold((0.0, 0.0, 0.0), |(ixx, iyy, ixy), (a, b)| {
+                    let cross = a.x * b.y - b.x * a.y;
+                    (
+                        ixx + cross * (a.y * a.y + a.y * b.y + b.y * b.y),
+                        iyy + cross * (a.x * a.x + a.x * b.x + b.x * b.x),
+                        ixy + cross * (a.x * b.y + 2.0 * a.x * a.y + 2.0 * b.x * b.y + b.x * a.y),
+                    )
+                });
+        let sign = double_area.signum();
+        let area = self.area();
+        let c = self.centroid();
+        let (cx, cy) = (c.x - self.point.x, c.y - self.point.y);
+        SecondMoment {
+            ixx: sign * ixx / 12.0 - area * cy * cy,
+            iyy: sign * iyy / 12.0 - area * cx * cx,
+            ixy: sign * ixy / 24.0 - area * cx * cy,
+        }
+    }
+}
+
+impl Measure for Point2D {
+    fn area(&self) -> f32 {
+        0.0
+    }
+
+    fn perimeter(&self) -> f32 {
+        0.0
+    }
+
+    fn centroid(&self) -> Point2D {
+        *self
+    }
+
+    fn second_moment(&self) -> SecondMoment {
+        SecondMoment::zero()
+    }
+}
+
+impl Affine2D {
+    fn measure<R>(&self, f: impl Fn(&dyn Measure) -> R) -> R {
+        match &self.shape {
+            Shape2D::Rectangle { rect } => f(&rect.embed_affine(&self.point)),
+            Shape2D::RoundedRectangle { rounded_rect } => {
+                f(&rounded_rect.embed_affine(&self.point))
+            }
+            Shape2D::Circle { circle } => f(&circle.embed_affine(&self.point)),
+            Shape2D::Line { line } => f(&line.embed_affine(&self.point)),
+            Shape2D::Segment { segment } => f(&segment.embed_affine(&self.point)),
+            Shape2D::Polygon { polygon } => f(&polygon.embed_affine(&self.point)),
+            Shape2D::Point => f(&self.point),
+        }
+    }
+}
+
+impl Measure for Affine2D {
+    fn area(&self) -> f32 {
+        self.measure(|m| m.area())
+    }
+
+    fn perimeter(&self) -> f32 {
+        self.measure(|m| m.perimeter())
+    }
+
+    fn centroid(&self) -> Point2D {
+        self.measure(|m| m.centroid())
+    }
+
+    fn second_moment(&self) -> SecondMoment {
+        self.measure(|m| m.second_moment())
+    }
+}
@@ -0,0 +1,162 @@
+// Conversions to and from nalgebra types, nalgebra-glm vectors are the same types
+
+use nalgebra::{Isometry2, Matrix3, Point2, Rotation2, Translation2, UnitComplex, Vector2};
+
+use crate::{
+    angle::Angle,
+    bounding::OrientedRectangle,
+    point::Point2D,
+    shapes::{
+        Shape2D, Shape2DType,
+        affine::{Affine2D, TypedAffine2D},
+        rectangle::Rectangle,
+    },
+    vector::Vector2D,
+    viewport::Viewport,
+};
+
+impl From<Point2D> for Point2<f32> {
+    fn from(p: Point2D) -> Self {
+        Point2::new(p.x, p.y)
+    }
+}
+
+impl From<Point2<f32>> for Point2D {
+    fn from(p: Point2<f32>) -> Self {
+        Point2D { x: p.x, y: p.y }
+    }
+}
+
+impl From<Vector2D> for Vector2<f32> {
+    fn from(v: Vector2D) -> Self {
+        Vector2::new(v.x, v.y)
+    }
+}
+
+impl From<Vector2<f32>> for Vector2D {
+    fn from(v: Vector2<f32>) -> Self {
+        Vector2D { x: v.x, y: v.y }
+    }
+}
+
+impl From<Vector2D> for Translation2<f32> {
+    fn from(v: Vector2D) -> Self {
+        Translation2::new(v.x, v.y)
+    }
+}
+
+impl From<Translation2<f32>> for Vector2D {
+    fn from(t: Translation2<f32>) -> Self {
+        Vector2D {
+            x: t.vector.x,
+            y: t.vector.y,
+        }
+    }
+}
+
+impl From<Angle> for UnitComplex<f32> {
+    fn from(angle: Angle) -> Self {
+        UnitComplex::new(angle.as_radians())
+    }
+}
+
+impl From<UnitComplex<f32>> for Angle {
+    fn from(rotation: UnitComplex<f32>) -> Self {
+        Angle::radians(rotation.angle())
+    }
+}
+
+impl From<Angle> for Rotation2<f32> {
+    fn from(angle: Angle) -> Self {
+        Rotation2::new(angle.as_radians())
+    }
+}
+
+impl From<Rotation2<f32>> for Angle {
+    fn from(rotation: Rotation2<f32>) -> Self {
+        Angle::radians(rotation.angle())
+    }
+}
+
+/// Moves the local frame of the rectangle, centered and turned with it, to world space
+impl From<&OrientedRectangle> for Isometry2<f32> {
+    fn from(rect: &OrientedRectangle) -> Self {
+        Isometry2::new(
+            Vector2::new(rect.center.x, rect.center.y),
+            rect.angle.as_radians(),
+        )
+    }
+}
+
+/// Rectangle placed and turned by the isometry, which maps its local frame to world space
+impl From<(Isometry2<f32>, Rectangle)> for OrientedRectangle {
+    fn from((isometry, rect): (Isometry2<f32>, Rectangle)) -> Self {
+        OrientedRectangle {
+            center: Point2D {
+                x: isometry.translation.vector.x,
+                y: isometry.translation.vector.y,
+            },
+            rect,
+            angle: isometry.rotation.into(),
+        }
+    }
+}
+
+// Affine shapes are only moved, never turned, so they convert to isometries without
+// rotation and are built back from a translation and the shape. Turned rectangles go
+// through `OrientedRectangle`.
+
+impl From<&Affine2D> for Translation2<f32> {
+    fn from(shape: &Affine2D) -> Self {
+        Translation2::new(shape.point.x, shape.point.y)
+    }
+}
+
+impl From<&Affine2D> for Isometry2<f32> {
+    fn from(shape: &Affine2D) -> Self {
+        Translation2::from(shape).into()
+    }
+}
+
+impl From<(Translation2<f32>, Shape2D)> for Affine2D {
+    fn from((translation, shape): (Translation2<f32>, Shape2D)) -> Self {
+        Affine2D::new(translation.vector.x, translation.vector.y, shape)
+    }
+}
+
+impl<T> From<&TypedAffine2D<T>> for Translation2<f32>
+where
+    T: Shape2DType,
+{
+    fn from(shape: &TypedAffine2D<T>) -> Self {
+        Translation2::new(shape.point.x, shape.point.y)
+    }
+}
+
+impl<T> From<&TypedAffine2D<T>> for Isometry2<f32>
+where
+    T: Shape2DType,
+{
+    fn from(shape: &TypedAffine2D<T>) -> Self {
+        Translation2::from(shape).into()
+    }
+}
+
+impl<T> From<(Translation2<f32>, T)> for TypedAffine2D<T>
+where
+    T: Shape2DType,
+{
+    fn from((translation, shape): (Translation2<f32>, T)) -> Self {
+        let point = Point2D {
+            x: translation.vector.x,
+            y: translation.vector.y,
+        };
+        TypedAffine2D::new(point, shape)
+    }
+}
+
+impl From<&Viewport> for Matrix3<f32> {
+    fn from(viewport: &Viewport) -> Self {
+        Matrix3::from(viewport.world_to_screen_matrix())
+    }
+}
@@ -0,0 +1,173 @@
+use std::f32::consts::PI;
+
+use crate::{
+    angle::Angle,
+    clipping::positive_fill,
+    comparators::is_zero,
+    point::Point2D,
+    shapes::{
+        polygon::{Polygon, PolygonWithHoles},
+        polyline::Polyline,
+    },
+    vector::Vector2D,
+};
+
+/// Largest angle spanned by a single segment of round joins and caps
+const ROUND_STEP: Angle = Angle::degrees(10.0);
+
+/// Shape of the offset outline at vertices where offset edges move apart
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum JoinStyle {
+    /// Edges are extended until they meet, unless the corner would stick out
+    /// further than `limit` times the offset distance, then it is beveled
+    Miter {
+        limit: f32,
+    },
+    Round,
+    Bevel,
+}
+
+/// Shape of the outline at both ends of an offset polyline
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EndCap {
+    Butt,
+    Square,
+    Round,
+}
+
+/// Grows polygon by `distance`, or shrinks it when `distance` is negative
+pub fn offset_polygon(
+    polygon: &PolygonWithHoles,
+    distance: f32,
+    join: JoinStyle,
+) -> Vec<PolygonWithHoles> {
+    if is_zero(distance.abs()) {
+        return vec![polygon.clone()];
+    }
+    let cap = match join {
+        JoinStyle::Round => EndCap::Round,
+        _ => EndCap::Butt,
+    };
+    let contours: Vec<Polygon> = polygon
+        .contours()
+        .map(|c| offset_contour(&c.vertices, distance, join, cap))
+        .collect();
+    positive_fill(&contours)
+}
+
+/// Outline of all points within `distance` of the polyline
+pub fn offset_polyline(
+    polyline: &Polyline,
+    distance: f32,
+    join: JoinStyle,
+    cap: EndCap,
+) -> Vec<PolygonWithHoles> {
+    let mut points = polyline.points.clone();
+    points.dedup_by(|a, b| a == b);
+    if points.len() < 2 || is_zero(distance.abs()) {
+        return vec![];
+    }
+    // walk there and back, so the polyline becomes a closed contour of zero area
+    let mut contour: Vec<Point2D> = points.iter().rev().copied().collect();
+    contour.extend(points[1..points.len() - 1].iter());
+    positive_fill(&[offset_contour(&contour, distance.abs(), join, cap)])
+}
+
+/// Moves every edge of a closed contour to its right side by `distance`, the result
+/// may intersect itself
+fn offset_contour(vertices: &[Point2D], distance: f32, join: JoinStyle, cap: EndCap) -> Polygon {
+    let mut points = vertices.to_vec();
+    points.dedup_by(|a, b| a == b);
+    while points.len() > 1 && points.first() == points.last() {
+        points.pop();
+    }
+    let n = points.len();
+    let directions: Vec<Option<Vector2D>> = (0..n)
+        .map(|i| (points[(i + 1) % n] - points[i]).normalized())
+        .collect();
+    let mut outline = Vec::new();
+    for i in 0..n {
+        let (Some(incoming), Some(outgoing)) = (&directions[(i + n - 1) % n], &directions[i])
+        else {
+            continue;
+        };
+        offset_vertex(
+            &mut outline,
+            &points[i],
+            incoming,
+            outgoing,
+            distance,
+            join,
+            cap,
+        );
+    }
+    Polygon::new(outline)
+}
+
+fn right_normal(direction: &Vector2D) -> Vector2D {
+    Vector2D {
+        x: direction.y,
+        y: -direction.x,
+    }
+}
+
+fn offset_vertex(
+    outline: &mut Vec<Point2D>,
+    vertex: &Point2D,
+    incoming: &Vector2D,
+    outgoing: &Vector2D,
+    distance: f32,
+    join: JoinStyle,
+    cap: EndCap,
+) {
+    let (n1, n2) = (right_normal(incoming), right_normal(outgoing));
+    let (q1, q2) = (
+        *vertex + n1.clone() * distance,
+        *vertex + n2.clone() * distance,
+    );
+    let (sin, cos) = (n1.cross(&n2), n1.dot(&n2));
+    if is_zero(sin.abs()) && cos < 0.0 {
+        // the contour turns back on itself
+        match cap {
+            EndCap::Butt => outline.extend([q1, q2]),
+            EndCap::Square => {
+                let extension = incoming.clone() * distance.abs();
+                outline.extend([q1 + extension.clone(), q2 + extension]);
+            }
+            EndCap::Round => add_arc(outline, vertex, &n1, PI * distance.signum(), distance),
+        }
+        return;
+    }
+    if distance * sin <= 0.0 || is_zero(sin.abs()) {
+        // offset edges overlap, going through the vertex lets winding numbers sort it out
+        outline.extend([q1, *vertex, q2]);
+        return;
+    }
+    match join {
+        JoinStyle::Bevel => outline.extend([q1, q2]),
+        JoinStyle::Round => add_arc(outline, vertex, &n1, f32::atan2(sin, cos), distance),
+        JoinStyle::Miter { limit } => {
+            let ratio = 1.0 / f32::sqrt((1.0 + cos) / 2.0);
+            if ratio > limit {
+                outline.extend([q1, q2]);
+            } else {
+                outline.push(*vertex + (n1 + n2) * (distance / (1.0 + cos)));
+            }
+        }
+    }
+}
+
+fn add_arc(
+    outline: &mut Vec<Point2D>,
+    center: &Point2D,
+    start: &Vector2D,
+    sweep: f32,
+    distance: f32,
+) {
+    let steps = (sweep.abs() / ROUND_STEP.as_radians()).ceil().max(1.0) as usize;
+    let start_angle = (start.clone() * distance).get_angle();
+    for step in 0..=steps {
+        let angle = start_angle.clone() + Angle::radians(sweep * step as f32 / steps as f32);
+        outline.push(*center + Vector2D::by_angle(&angle) * distance.abs());
+    }
+}
@@ -3,6 +3,7 @@ use std::ops::{Add, Sub};
 use crate::{comparators::is_zero, vector::Vector2D};
 
 #[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Point2D {
     pub x: f32,
     pub y: f32,
@@ -49,7 +50,7 @@ impl Sub<Point2D> for Point2D {
 
 impl PartialEq for Point2D {
     fn eq(&self, other: &Point2D) -> bool {
-        let diff: Vector2D = self.clone() - other.clone();
+        let diff: Vector2D = *self - *other;
         is_zero(diff.length())
     }
 }
@@ -0,0 +1,70 @@
+use crate::{comparators::is_zero, point::Point2D, shapes::polyline::Polyline, vector::Vector2D};
+
+pub mod orthogonal;
+pub mod spline;
+
+/// Side of a node a connector leaves or enters through
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PortSide {
+    Left,
+    Right,
+    Top,
+    Bottom,
+}
+
+impl PortSide {
+    pub fn get_normal(&self) -> Vector2D {
+        match self {
+            PortSide::Left => Vector2D { x: -1.0, y: 0.0 },
+            PortSide::Right => Vector2D { x: 1.0, y: 0.0 },
+            PortSide::Top => Vector2D { x: 0.0, y: 1.0 },
+            PortSide::Bottom => Vector2D { x: 0.0, y: -1.0 },
+        }
+    }
+}
+
+/// End of a connector, a port with a side leaves its node perpendicularly to that side
+#[derive(Debug, Clone, Copy)]
+pub struct Port {
+    pub point: Point2D,
+    pub side: Option<PortSide>,
+}
+
+impl Port {
+    pub fn new(point: Point2D) -> Port {
+        Port { point, side: None }
+    }
+
+    pub fn on_side(point: Point2D, side: PortSide) -> Port {
+        Port {
+            point,
+            side: Some(side),
+        }
+    }
+
+    /// First point of the route outside the obstacle margin
+    fn get_escape_point(&self, margin: f32) -> Point2D {
+        match self.side {
+            Some(side) => self.point + side.get_normal() * margin,
+            None => self.point,
+        }
+    }
+}
+
+/// Drops repeated points and middle points of straight runs
+fn simplify(points: Vec<Point2D>) -> Polyline {
+    let mut simplified: Vec<Point2D> = Vec::with_capacity(points.len());
+    for p in points {
+        if simplified.last() == Some(&p) {
+            continue;
+        }
+        if let [.., a, b] = simplified[..]
+            && is_zero((b - a).cross(&(p - b)).abs())
+            && (b - a).dot(&(p - b)) > 0.0
+        {
+            simplified.pop();
+        }
+        simplified.push(p);
+    }
+    Polyline::new(simplified)
+}
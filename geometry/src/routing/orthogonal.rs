@@ -0,0 +1,272 @@
+use std::{cmp::Ordering, collections::BinaryHeap, ops::Range};
+
+use super::{Port, PortSide, simplify};
+use crate::{
+    collisions::collision_predicate_functions::check_collision_rect_point,
+    comparators::{are_equal, less_or_equal},
+    point::{Point2D, dist},
+    shapes::{affine::TypedAffine2D, polyline::Polyline, rectangle::Rectangle},
+};
+
+/// Routes run this much closer to obstacles than the margin, so that routes running
+/// exactly at the margin do not count as collisions
+const ROUTE_TOLERANCE: f32 = 0.01;
+
+/// Grid steps of the directions right, up, left and down
+const DIRECTIONS: [(isize, isize); 4] = [(1, 0), (0, 1), (-1, 0), (0, -1)];
+
+/// Direction index of a state that has not moved yet
+const ANY_DIRECTION: usize = 4;
+
+fn side_direction(side: PortSide) -> usize {
+    match side {
+        PortSide::Right => 0,
+        PortSide::Top => 1,
+        PortSide::Left => 2,
+        PortSide::Bottom => 3,
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Cost {
+    bends: u32,
+    length: f32,
+}
+
+impl Cost {
+    fn cmp(&self, other: &Cost) -> Ordering {
+        self.bends
+            .cmp(&other.bends)
+            .then(self.length.total_cmp(&other.length))
+    }
+}
+
+#[derive(Debug)]
+struct QueueEntry {
+    cost: Cost,
+    state: usize,
+}
+
+impl PartialEq for QueueEntry {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for QueueEntry {}
+
+impl PartialOrd for QueueEntry {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for QueueEntry {
+    // reversed, so that the binary heap pops the cheapest entry first
+    fn cmp(&self, other: &Self) -> Ordering {
+        other.cost.cmp(&self.cost)
+    }
+}
+
+/// Sparse grid made of the lines running along obstacle margins and through the ports
+struct RoutingGrid {
+    xs: Vec<f32>,
+    ys: Vec<f32>,
+    /// Edges from a node to its right and to its upper neighbour that run into a clearance
+    blocked_right: Vec<bool>,
+    blocked_up: Vec<bool>,
+}
+
+fn lines_within(coords: &[f32], min: f32, max: f32) -> Range<usize> {
+    let first = coords.partition_point(|&v| !less_or_equal(min, v));
+    let end = coords.partition_point(|&v| less_or_equal(v, max));
+    first..end.max(first)
+}
+
+fn edges_within(coords: &[f32], min: f32, max: f32) -> Range<usize> {
+    let lines = lines_within(coords, min, max);
+    let first = lines.start.saturating_sub(1);
+    first..lines.end.min(coords.len() - 1).max(first)
+}
+
+impl RoutingGrid {
+    /// Grid over the lines, grid edges are checked against the axis aligned clearances
+    /// once here instead of on every step of the search
+    fn new(xs: Vec<f32>, ys: Vec<f32>, clearances: &[TypedAffine2D<Rectangle>]) -> RoutingGrid {
+        let mut blocked_right = vec![false; xs.len() * ys.len()];
+        let mut blocked_up = vec![false; xs.len() * ys.len()];
+        for c in clearances {
+            let (left, right) = (c.get_left(), c.get_right());
+            let (bottom, top) = (c.get_bottom(), c.get_top());
+            for y in lines_within(&ys, bottom, top) {
+                for x in edges_within(&xs, left, right) {
+                    blocked_right[y * xs.len() + x] = true;
+                }
+            }
+            for y in edges_within(&ys, bottom, top) {
+                for x in lines_within(&xs, left, right) {
+                    blocked_up[y * xs.len() + x] = true;
+                }
+            }
+        }
+        RoutingGrid {
+            xs,
+            ys,
+            blocked_right,
+            blocked_up,
+        }
+    }
+
+    fn point(&self, node: usize) -> Point2D {
+        Point2D {
+            x: self.xs[node % self.xs.len()],
+            y: self.ys[node / self.xs.len()],
+        }
+    }
+
+    fn node(&self, p: &Point2D) -> usize {
+        let index = |coords: &[f32], c: f32| {
+            coords
+                .iter()
+                .position(|&v| are_equal(v, c))
+                .expect("port coordinates are grid lines")
+        };
+        index(&self.ys, p.y) * self.xs.len() + index(&self.xs, p.x)
+    }
+
+    fn step(&self, node: usize, direction: usize) -> Option<usize> {
+        let (dx, dy) = DIRECTIONS[direction];
+        let x = (node % self.xs.len()).checked_add_signed(dx)?;
+        let y = (node / self.xs.len()).checked_add_signed(dy)?;
+        if x >= self.xs.len() || y >= self.ys.len() {
+            return None;
+        }
+        let next = y * self.xs.len() + x;
+        let blocked = match direction {
+            0 => self.blocked_right[node],
+            1 => self.blocked_up[node],
+            2 => self.blocked_right[next],
+            _ => self.blocked_up[next],
+        };
+        (!blocked).then_some(next)
+    }
+}
+
+fn grid_lines(mut coords: Vec<f32>) -> Vec<f32> {
+    coords.sort_by(f32::total_cmp);
+    coords.dedup_by(|a, b| are_equal(*a, *b));
+    coords
+}
+
+/// Orthogonal route with the fewest bends, then the shortest length, that stays at least
+/// `margin` away from every obstacle. Obstacles containing a port without a side are
+/// ignored, `None` if the target cannot be reached.
+pub fn route_orthogonal(
+    obstacles: &[TypedAffine2D<Rectangle>],
+    source: &Port,
+    target: &Port,
+    margin: f32,
+) -> Option<Polyline> {
+    let (start, end) = (
+        source.get_escape_point(margin),
+        target.get_escape_point(margin),
+    );
+    let clearances: Vec<TypedAffine2D<Rectangle>> = obstacles
+        .iter()
+        .map(|o| o.inflated(margin - ROUTE_TOLERANCE))
+        .filter(|c| {
+            [source, target]
+                .iter()
+                .all(|port| port.side.is_some() || !check_collision_rect_point(c, &port.point))
+        })
+        .collect();
+    let margins: Vec<TypedAffine2D<Rectangle>> =
+        obstacles.iter().map(|o| o.inflated(margin)).collect();
+    let grid = RoutingGrid::new(
+        grid_lines(
+            margins
+                .iter()
+                .flat_map(|m| [m.get_left(), m.get_right()])
+                .chain([start.x, end.x])
+                .collect(),
+        ),
+        grid_lines(
+            margins
+                .iter()
+                .flat_map(|m| [m.get_bottom(), m.get_top()])
+                .chain([start.y, end.y])
+                .collect(),
+        ),
+        &clearances,
+    );
+    let (start_node, end_node) = (grid.node(&start), grid.node(&end));
+    let start_direction = source.side.map_or(ANY_DIRECTION, side_direction);
+    let arrival_direction = target.side.map(|side| (side_direction(side) + 2) % 4);
+
+    // Dijkstra over (node, direction of the last move) states
+    let state_count = grid.xs.len() * grid.ys.len() * 5;
+    let mut costs: Vec<Option<Cost>> = vec![None; state_count];
+    let mut previous: Vec<Option<usize>> = vec![None; state_count];
+    let mut queue = BinaryHeap::new();
+    let start_state = start_node * 5 + start_direction;
+    let zero = Cost {
+        bends: 0,
+        length: 0.0,
+    };
+    costs[start_state] = Some(zero);
+    queue.push(QueueEntry {
+        cost: zero,
+        state: start_state,
+    });
+    while let Some(QueueEntry { cost, state }) = queue.pop() {
+        if costs[state].is_some_and(|c| c.cmp(&cost) == Ordering::Less) {
+            continue;
+        }
+        let (node, direction) = (state / 5, state % 5);
+        for next_direction in 0..4 {
+            if direction != ANY_DIRECTION && next_direction == (direction + 2) % 4 {
+                continue;
+            }
+            let Some(next) = grid.step(node, next_direction) else {
+                continue;
+            };
+            let next_cost = Cost {
+                bends: cost.bends
+                    + u32::from(direction != ANY_DIRECTION && direction != next_direction),
+                length: cost.length + dist(&grid.point(node), &grid.point(next)),
+            };
+            let next_state = next * 5 + next_direction;
+            if costs[next_state].is_none_or(|c| next_cost.cmp(&c) == Ordering::Less) {
+                costs[next_state] = Some(next_cost);
+                previous[next_state] = Some(state);
+                queue.push(QueueEntry {
+                    cost: next_cost,
+                    state: next_state,
+                });
+            }
+        }
+    }
+
+    // arriving at a side port from any other direction needs one more bend
+    let end_state = (0..5)
+        .map(|direction| end_node * 5 + direction)
+        .filter_map(|state| {
+            let mut cost = costs[state]?;
+            let direction = state % 5;
+            if direction != ANY_DIRECTION && arrival_direction.is_some_and(|d| d != direction) {
+                cost.bends += 1;
+            }
+            Some((state, cost))
+        })
+        .min_by(|(_, c1), (_, c2)| c1.cmp(c2))?
+        .0;
+    let mut path = vec![target.point];
+    let mut state = Some(end_state);
+    while let Some(s) = state {
+        path.push(grid.point(s / 5));
+        state = previous[s];
+    }
+    path.push(source.point);
+    path.reverse();
+    Some(simplify(path))
+}
@@ -0,0 +1,189 @@
+use std::f32::consts::PI;
+
+use crate::{
+    angle::Angle,
+    bounding::{bounding_box_of_points, convex_hull, get_hull_points},
+    collisions::collision_predicate_functions::{
+        check_collision_polygon_point, check_collision_polygon_segment,
+    },
+    comparators::less_or_equal,
+    point::{Point2D, dist},
+    shapes::{
+        affine::{Affine2D, EmbedInAffine2D, TypedAffine2D},
+        bezier::CubicBezier,
+        polygon::Polygon,
+        polyline::Polyline,
+        rectangle::Rectangle,
+        segment::Segment2D,
+    },
+    vector::Vector2D,
+};
+
+/// Routes may run this much closer to obstacles than the margin, so that routes going
+/// along padded obstacle borders do not count as collisions
+const ROUTE_TOLERANCE: f32 = 0.01;
+
+/// Sides of the polygons circumscribing circles and obstacle margins
+const CIRCLE_SEGMENTS: usize = 16;
+
+/// Convex hull of the shape grown by at least `distance` in every direction
+fn padded_hull(shape: &Affine2D, distance: f32) -> Option<TypedAffine2D<Polygon>> {
+    let points = get_hull_points(shape, CIRCLE_SEGMENTS)?;
+    let radius = distance.max(0.0) / f32::cos(PI / CIRCLE_SEGMENTS as f32);
+    let offsets: Vec<Vector2D> = (0..CIRCLE_SEGMENTS)
+        .map(|i| {
+            let angle = Angle::radians(2.0 * PI * i as f32 / CIRCLE_SEGMENTS as f32);
+            Vector2D::by_angle(&angle) * radius
+        })
+        .collect();
+    let grown: Vec<Point2D> = points
+        .iter()
+        .flat_map(|p| offsets.iter().map(|o| *p + o.clone()))
+        .collect();
+    let hull = convex_hull(&grown);
+    let center = shape.point;
+    let vertices = hull
+        .iter()
+        .map(|v| Point2D::zero() + (*v - center))
+        .collect();
+    Some(Polygon::new(vertices).embed_affine(&center))
+}
+
+/// Shortest polyline from `source` to `target` keeping at least `margin` away from every
+/// obstacle, found on the visibility graph of padded obstacle hulls. Unbounded obstacles
+/// and obstacles containing an end point are ignored, `None` if the target cannot be reached.
+pub fn shortest_visible_path(
+    obstacles: &[Affine2D],
+    source: &Point2D,
+    target: &Point2D,
+    margin: f32,
+) -> Option<Polyline> {
+    let mut clearances: Vec<TypedAffine2D<Polygon>> = vec![];
+    let mut nodes = vec![*source, *target];
+    for obstacle in obstacles {
+        let (Some(clearance), Some(padded)) = (
+            padded_hull(obstacle, margin - ROUTE_TOLERANCE),
+            padded_hull(obstacle, margin),
+        ) else {
+            continue;
+        };
+        if check_collision_polygon_point(&clearance, source)
+            || check_collision_polygon_point(&clearance, target)
+        {
+            continue;
+        }
+        clearances.push(clearance);
+        nodes.extend(padded.get_vertices());
+    }
+    let boxes: Vec<TypedAffine2D<Rectangle>> = clearances
+        .iter()
+        .map(|c| bounding_box_of_points(&c.get_vertices()).expect("hulls are not empty"))
+        .collect();
+    // clearances whose bounding box overlaps the box from `min` to `max`
+    let near = |min: Point2D, max: Point2D| {
+        clearances.iter().zip(&boxes).filter(move |(_, bounds)| {
+            less_or_equal(bounds.get_left(), max.x)
+                && less_or_equal(min.x, bounds.get_right())
+                && less_or_equal(bounds.get_bottom(), max.y)
+                && less_or_equal(min.y, bounds.get_top())
+        })
+    };
+    // hull nodes inside another clearance are never visible, the end points stay
+    let nodes: Vec<Point2D> = nodes
+        .into_iter()
+        .enumerate()
+        .filter(|(i, p)| *i < 2 || !near(*p, *p).any(|(c, _)| check_collision_polygon_point(c, p)))
+        .map(|(_, p)| p)
+        .collect();
+    let is_visible = |a: &Point2D, b: &Point2D| {
+        let segment = TypedAffine2D::<Segment2D>::from_points(a, b);
+        let min = Point2D {
+            x: a.x.min(b.x),
+            y: a.y.min(b.y),
+        };
+        let max = Point2D {
+            x: a.x.max(b.x),
+            y: a.y.max(b.y),
+        };
+        !near(min, max).any(|(c, _)| check_collision_polygon_segment(c, &segment))
+    };
+
+    // Dijkstra on the visibility graph, edges are tested when first relaxed
+    let n = nodes.len();
+    let mut distances = vec![f32::INFINITY; n];
+    let mut previous: Vec<Option<usize>> = vec![None; n];
+    let mut done = vec![false; n];
+    distances[0] = 0.0;
+    while let Some(current) = (0..n)
+        .filter(|&i| !done[i] && distances[i].is_finite())
+        .min_by(|&i, &j| distances[i].total_cmp(&distances[j]))
+    {
+        if current == 1 {
+            break;
+        }
+        done[current] = true;
+        for next in 0..n {
+            let length = distances[current] + dist(&nodes[current], &nodes[next]);
+            if !done[next] && length < distances[next] && is_visible(&nodes[current], &nodes[next])
+            {
+                distances[next] = length;
+                previous[next] = Some(current);
+            }
+        }
+    }
+    if !distances[1].is_finite() {
+        return None;
+    }
+    let mut path = vec![nodes[1]];
+    let mut node = 1;
+    while let Some(p) = previous[node] {
+        path.push(nodes[p]);
+        node = p;
+    }
+    path.reverse();
+    Some(Polyline::new(path))
+}
+
+/// Replaces polyline corners by curves cutting at most `radius` off both adjacent
+/// segments, and no more than half of each segment
+pub fn smooth_corners(polyline: &Polyline, radius: f32) -> Vec<CubicBezier> {
+    let mut points = polyline.points.clone();
+    points.dedup_by(|a, b| a == b);
+    let mut curves = Vec::with_capacity(2 * points.len());
+    let Some(&first) = points.first() else {
+        return curves;
+    };
+    let mut current = first;
+    for w in points.windows(3) {
+        let (incoming, outgoing) = (w[1] - w[0], w[2] - w[1]);
+        let cut = radius
+            .min(incoming.length() / 2.0)
+            .min(outgoing.length() / 2.0);
+        let (Some(u_in), Some(u_out)) = (incoming.normalized(), outgoing.normalized()) else {
+            continue;
+        };
+        let (corner_start, corner_end) = (w[1] - u_in * cut, w[1] + u_out * cut);
+        if current != corner_start {
+            curves.push(CubicBezier::line(current, corner_start));
+        }
+        curves.push(CubicBezier::from_quadratic(corner_start, w[1], corner_end));
+        current = corner_end;
+    }
+    let last = *points.last().expect("points are not empty");
+    if current != last {
+        curves.push(CubicBezier::line(current, last));
+    }
+    curves
+}
+
+/// Smooth route made of cubic Bézier curves around obstacles, corners of the shortest
+/// visible path are rounded within the margin
+pub fn route_spline(
+    obstacles: &[Affine2D],
+    source: &Point2D,
+    target: &Point2D,
+    margin: f32,
+) -> Option<Vec<CubicBezier>> {
+    let path = shortest_visible_path(obstacles, source, target, margin)?;
+    Some(smooth_corners(&path, margin))
+}
@@ -3,6 +3,7 @@ use crate::point::Point2D;
 use super::{Shape2D, Shape2DType};
 
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Affine2D {
     pub shape: Shape2D,
     pub point: Point2D,
@@ -17,6 +18,7 @@ impl Affine2D {
 }
 
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct TypedAffine2D<T>
 where
     T: Shape2DType,
@@ -0,0 +1,153 @@
+use std::f32::consts::{FRAC_PI_2, PI, TAU};
+
+use super::{bezier::CubicBezier, polyline::Polyline};
+use crate::{angle::Angle, comparators::is_zero, point::Point2D, vector::Vector2D};
+
+/// Arc of an ellipse in world coordinates, points are `center + rotation(radius_x * cos a,
+/// radius_y * sin a)` for parameter angles `a` from `start` to `start + sweep`
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct EllipticalArc {
+    pub center: Point2D,
+    pub radius_x: f32,
+    pub radius_y: f32,
+    /// Turn of the x radius away from the x axis
+    pub rotation: Angle,
+    pub start: Angle,
+    /// Positive sweeps run counter-clockwise
+    pub sweep: Angle,
+}
+
+impl EllipticalArc {
+    /// Arc given the SVG way, by its end points and flags choosing one of the four arcs
+    /// through them. Radii too small to reach are scaled up, `None` when the end points
+    /// are equal or a radius is zero.
+    pub fn from_end_points(
+        from: &Point2D,
+        to: &Point2D,
+        radius_x: f32,
+        radius_y: f32,
+        rotation: &Angle,
+        large_arc: bool,
+        sweep_positive: bool,
+    ) -> Option<EllipticalArc> {
+        let (mut rx, mut ry) = (radius_x.abs(), radius_y.abs());
+        if is_zero(rx) || is_zero(ry) || *from == *to {
+            return None;
+        }
+        let (sin, cos) = (rotation.sin(), rotation.cos());
+        // end points in the frame of the unturned ellipse, centered between them
+        let half = (*from - *to) / 2.0;
+        let x = cos * half.x + sin * half.y;
+        let y = -sin * half.x + cos * half.y;
+        let scale = (x * x) / (rx * rx) + (y * y) / (ry * ry);
+        if scale > 1.0 {
+            rx *= scale.sqrt();
+            ry *= scale.sqrt();
+        }
+        let numerator = (rx * rx * ry * ry - rx * rx * y * y - ry * ry * x * x).max(0.0);
+        let denominator = rx * rx * y * y + ry * ry * x * x;
+        let sign = if large_arc == sweep_positive {
+            -1.0
+        } else {
+            1.0
+        };
+        let k = sign * (numerator / denominator).sqrt();
+        let (cx, cy) = (k * rx * y / ry, -k * ry * x / rx);
+        let middle = *to + half;
+        let center = middle
+            + Vector2D {
+                x: cos * cx - sin * cy,
+                y: sin * cx + cos * cy,
+            };
+        let start = Vector2D {
+            x: (x - cx) / rx,
+            y: (y - cy) / ry,
+        };
+        let end = Vector2D {
+            x: (-x - cx) / rx,
+            y: (-y - cy) / ry,
+        };
+        let mut sweep = start.cross(&end).atan2(start.dot(&end));
+        if sweep_positive && sweep < 0.0 {
+            sweep += TAU;
+        } else if !sweep_positive && sweep > 0.0 {
+            sweep -= TAU;
+        }
+        Some(EllipticalArc {
+            center,
+            radius_x: rx,
+            radius_y: ry,
+            rotation: rotation.clone(),
+            start: start.get_angle(),
+            sweep: Angle::radians(sweep),
+        })
+    }
+
+    fn point_at_angle(&self, a: f32) -> Point2D {
+        let (sin, cos) = (self.rotation.sin(), self.rotation.cos());
+        let (x, y) = (self.radius_x * a.cos(), self.radius_y * a.sin());
+        self.center
+            + Vector2D {
+                x: cos * x - sin * y,
+                y: sin * x + cos * y,
+            }
+    }
+
+    fn tangent_at_angle(&self, a: f32) -> Vector2D {
+        let (sin, cos) = (self.rotation.sin(), self.rotation.cos());
+        let (x, y) = (-self.radius_x * a.sin(), self.radius_y * a.cos());
+        Vector2D {
+            x: cos * x - sin * y,
+            y: sin * x + cos * y,
+        }
+    }
+
+    /// Point at parameter `t` in [0, 1]
+    pub fn point_at(&self, t: f32) -> Point2D {
+        self.point_at_angle(self.start.as_radians() + self.sweep.as_radians() * t)
+    }
+
+    pub fn get_start(&self) -> Point2D {
+        self.point_at(0.0)
+    }
+
+    pub fn get_end(&self) -> Point2D {
+        self.point_at(1.0)
+    }
+
+    /// Cubic curves following the arc, one for every started quarter turn
+    pub fn to_beziers(&self) -> Vec<CubicBezier> {
+        let sweep = self.sweep.as_radians();
+        let pieces = ((sweep.abs() / FRAC_PI_2).ceil() as usize).max(1);
+        let step = sweep / pieces as f32;
+        let k = 4.0 / 3.0 * (step / 4.0).tan();
+        (0..pieces)
+            .map(|i| {
+                let a = self.start.as_radians() + step * i as f32;
+                let b = a + step;
+                let (start, end) = (self.point_at_angle(a), self.point_at_angle(b));
+                CubicBezier::new(
+                    start,
+                    start + self.tangent_at_angle(a) * k,
+                    end - self.tangent_at_angle(b) * k,
+                    end,
+                )
+            })
+            .collect()
+    }
+
+    /// Approximation by `segments` straight segments of equal parameter steps
+    pub fn flatten(&self, segments: usize) -> Polyline {
+        let segments = segments.max(1);
+        Polyline::new(
+            (0..=segments)
+                .map(|i| self.point_at(i as f32 / segments as f32))
+                .collect(),
+        )
+    }
+
+    pub fn is_large(&self) -> bool {
+        self.sweep.as_radians().abs() > PI
+    }
+}
@@ -0,0 +1,61 @@
+use super::polyline::Polyline;
+use crate::point::Point2D;
+
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CubicBezier {
+    pub start: Point2D,
+    pub control1: Point2D,
+    pub control2: Point2D,
+    pub end: Point2D,
+}
+
+impl CubicBezier {
+    pub fn new(start: Point2D, control1: Point2D, control2: Point2D, end: Point2D) -> CubicBezier {
+        CubicBezier {
+            start,
+            control1,
+            control2,
+            end,
+        }
+    }
+
+    pub fn line(start: Point2D, end: Point2D) -> CubicBezier {
+        CubicBezier::new(
+            start,
+            start + (end - start) / 3.0,
+            end + (start - end) / 3.0,
+            end,
+        )
+    }
+
+    /// Exact cubic form of a quadratic curve
+    pub fn from_quadratic(start: Point2D, control: Point2D, end: Point2D) -> CubicBezier {
+        CubicBezier::new(
+            start,
+            start + (control - start) * (2.0 / 3.0),
+            end + (control - end) * (2.0 / 3.0),
+            end,
+        )
+    }
+
+    pub fn point_at(&self, t: f32) -> Point2D {
+        let s = 1.0 - t;
+        let weights = [s * s * s, 3.0 * s * s * t, 3.0 * s * t * t, t * t * t];
+        let points = [self.start, self.control1, self.control2, self.end];
+        Point2D {
+            x: weights.iter().zip(&points).map(|(w, p)| w * p.x).sum(),
+            y: weights.iter().zip(&points).map(|(w, p)| w * p.y).sum(),
+        }
+    }
+
+    /// Approximation by `segments` straight segments of equal parameter steps
+    pub fn flatten(&self, segments: usize) -> Polyline {
+        let segments = segments.max(1);
+        Polyline::new(
+            (0..=segments)
+                .map(|i| self.point_at(i as f32 / segments as f32))
+                .collect(),
+        )
+    }
+}
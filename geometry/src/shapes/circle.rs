@@ -1,6 +1,7 @@
 use super::{Shape2D, Shape2DType};
 
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Circle {
     pub radius: f32,
 }
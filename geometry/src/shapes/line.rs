@@ -5,9 +5,11 @@ use super::{
 use crate::{
     angle::Angle,
     point::{Point2D, dist2},
+    vector::Vector2D,
 };
 
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Line2D {
     pub angle: Angle,
 }
@@ -19,14 +21,29 @@ impl From<Line2D> for Shape2D {
 }
 
 impl Line2D {
+    pub fn get_direction(&self) -> Vector2D {
+        Vector2D::by_angle(&self.angle)
+    }
+
     pub fn get_perpendicular(&self) -> Line2D {
         Line2D {
-            angle: Angle::degrees(self.angle.as_degrees() + 90.0).normalized(),
+            angle: (self.angle.clone() + Angle::degrees(90.0)).normalized(),
         }
     }
 }
 
 impl TypedAffine2D<Line2D> {
+    /// Creates line passing through both points, `None` if points are equal
+    pub fn from_points(p1: &Point2D, p2: &Point2D) -> Option<TypedAffine2D<Line2D>> {
+        let direction = (*p2 - *p1).normalized()?;
+        Some(
+            Line2D {
+                angle: direction.get_angle(),
+            }
+            .embed_affine(p1),
+        )
+    }
+
     fn get_intersection_point_with_vertical(l: &TypedAffine2D<Line2D>, x: f32) -> Point2D {
         let a = l.shape.angle.tan();
         let b = l.point.y - a * l.point.x;
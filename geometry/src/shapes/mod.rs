@@ -1,20 +1,30 @@
 use circle::Circle;
 use line::Line2D;
+use polygon::Polygon;
 use rectangle::Rectangle;
+use rounded_rectangle::RoundedRectangle;
 use segment::Segment2D;
 
 pub mod affine;
+pub mod arc;
+pub mod bezier;
 pub mod circle;
 pub mod line;
+pub mod polygon;
+pub mod polyline;
 pub mod rectangle;
+pub mod rounded_rectangle;
 pub mod segment;
 
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Shape2D {
     Rectangle { rect: Rectangle },
+    RoundedRectangle { rounded_rect: RoundedRectangle },
     Circle { circle: Circle },
     Line { line: Line2D },
     Segment { segment: Segment2D },
+    Polygon { polygon: Polygon },
     Point,
 }
 
@@ -0,0 +1,141 @@
+use super::{
+    Shape2D, Shape2DType, affine::TypedAffine2D, rectangle::Rectangle, segment::Segment2D,
+};
+use crate::{point::Point2D, vector::Vector2D};
+
+/// Closed polygon, vertices are relative to the point it is embedded at
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Polygon {
+    pub vertices: Vec<Point2D>,
+}
+impl Shape2DType for Polygon {}
+
+impl From<Polygon> for Shape2D {
+    fn from(polygon: Polygon) -> Self {
+        Shape2D::Polygon { polygon }
+    }
+}
+
+impl Polygon {
+    pub fn new(vertices: Vec<Point2D>) -> Polygon {
+        Polygon { vertices }
+    }
+
+    /// Twice the signed area, positive for counter-clockwise vertex order
+    pub fn signed_double_area(&self) -> f32 {
+        self.get_edges().map(|(a, b)| a.x * b.y - b.x * a.y).sum()
+    }
+
+    pub fn is_counter_clockwise(&self) -> bool {
+        self.signed_double_area() > 0.0
+    }
+
+    pub fn reversed(&self) -> Polygon {
+        Polygon::new(self.vertices.iter().rev().copied().collect())
+    }
+
+    /// Same polygon with vertices in counter-clockwise order if `ccw`, clockwise otherwise
+    pub fn oriented(self, ccw: bool) -> Polygon {
+        if self.is_counter_clockwise() == ccw {
+            self
+        } else {
+            self.reversed()
+        }
+    }
+
+    /// Even-odd test of a point given in polygon coordinates, points on the border may
+    /// land on either side
+    pub fn winds_around(&self, point: &Point2D) -> bool {
+        let mut inside = false;
+        for (a, b) in self.get_edges() {
+            if (a.y > point.y) != (b.y > point.y) {
+                let x = a.x + (point.y - a.y) / (b.y - a.y) * (b.x - a.x);
+                if point.x < x {
+                    inside = !inside;
+                }
+            }
+        }
+        inside
+    }
+
+    /// Pairs of consecutive vertices, including the closing edge
+    pub fn get_edges(&self) -> impl Iterator<Item = (Point2D, Point2D)> + '_ {
+        let next = self.vertices.iter().cycle().skip(1);
+        self.vertices.iter().copied().zip(next.copied())
+    }
+}
+
+impl Polygon {
+    pub(crate) fn vertices_at(&self, at: &Point2D) -> Vec<Point2D> {
+        self.vertices
+            .iter()
+            .map(|v| *at + (*v - Point2D::zero()))
+            .collect()
+    }
+
+    pub(crate) fn segments_at(&self, at: &Point2D) -> Vec<TypedAffine2D<Segment2D>> {
+        let offset: Vector2D = *at - Point2D::zero();
+        self.get_edges()
+            .map(|(a, b)| {
+                TypedAffine2D::<Segment2D>::from_points(
+                    &(a + offset.clone()),
+                    &(b + offset.clone()),
+                )
+            })
+            .collect()
+    }
+}
+
+impl TypedAffine2D<Polygon> {
+    pub fn get_vertices(&self) -> Vec<Point2D> {
+        self.shape.vertices_at(&self.point)
+    }
+
+    pub fn get_segments(&self) -> Vec<TypedAffine2D<Segment2D>> {
+        self.shape.segments_at(&self.point)
+    }
+}
+
+/// Polygon with holes in world coordinates, the outer contour is counter-clockwise
+/// and holes are clockwise
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PolygonWithHoles {
+    pub outer: Polygon,
+    pub holes: Vec<Polygon>,
+}
+
+impl PolygonWithHoles {
+    /// Reorders vertices of contours to match the expected orientation
+    pub fn new(outer: Polygon, holes: Vec<Polygon>) -> PolygonWithHoles {
+        PolygonWithHoles {
+            outer: outer.oriented(true),
+            holes: holes.into_iter().map(|h| h.oriented(false)).collect(),
+        }
+    }
+
+    pub fn contours(&self) -> impl Iterator<Item = &Polygon> {
+        std::iter::once(&self.outer).chain(self.holes.iter())
+    }
+
+    pub fn area(&self) -> f32 {
+        self.contours().map(|c| c.signed_double_area()).sum::<f32>() / 2.0
+    }
+
+    pub fn winds_around(&self, point: &Point2D) -> bool {
+        self.outer.winds_around(point) && !self.holes.iter().any(|h| h.winds_around(point))
+    }
+}
+
+impl From<TypedAffine2D<Polygon>> for PolygonWithHoles {
+    fn from(polygon: TypedAffine2D<Polygon>) -> Self {
+        PolygonWithHoles::new(Polygon::new(polygon.get_vertices()), vec![])
+    }
+}
+
+impl From<TypedAffine2D<Rectangle>> for PolygonWithHoles {
+    fn from(rect: TypedAffine2D<Rectangle>) -> Self {
+        PolygonWithHoles::new(Polygon::new(rect.get_vertices().to_vec()), vec![])
+    }
+}
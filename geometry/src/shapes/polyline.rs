@@ -0,0 +1,25 @@
+use super::{affine::TypedAffine2D, segment::Segment2D};
+use crate::point::{Point2D, dist};
+
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Polyline {
+    pub points: Vec<Point2D>,
+}
+
+impl Polyline {
+    pub fn new(points: Vec<Point2D>) -> Polyline {
+        Polyline { points }
+    }
+
+    pub fn length(&self) -> f32 {
+        self.points.windows(2).map(|w| dist(&w[0], &w[1])).sum()
+    }
+
+    pub fn get_segments(&self) -> Vec<TypedAffine2D<Segment2D>> {
+        self.points
+            .windows(2)
+            .map(|w| TypedAffine2D::<Segment2D>::from_points(&w[0], &w[1]))
+            .collect()
+    }
+}
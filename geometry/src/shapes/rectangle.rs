@@ -1,4 +1,4 @@
-use crate::{angle::Angle, vector::Vector2D};
+use crate::{angle::Angle, point::Point2D, vector::Vector2D};
 
 use super::{
     Shape2D, Shape2DType,
@@ -7,6 +7,7 @@ use super::{
 };
 
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Rectangle {
     pub width: f32,
     pub height: f32,
@@ -33,6 +34,38 @@ impl TypedAffine2D<Rectangle> {
         self.point.y - self.shape.height / 2.0
     }
 
+    /// Same rectangle grown by `distance` on every side, never smaller than a point
+    pub fn inflated(&self, distance: f32) -> TypedAffine2D<Rectangle> {
+        Rectangle {
+            width: (self.shape.width + 2.0 * distance).max(0.0),
+            height: (self.shape.height + 2.0 * distance).max(0.0),
+        }
+        .embed_affine(&self.point)
+    }
+
+    /// Point of the rectangle nearest to `point`, `point` itself when inside
+    pub fn closest_point(&self, point: &Point2D) -> Point2D {
+        Point2D {
+            x: point.x.clamp(self.get_left(), self.get_right()),
+            y: point.y.clamp(self.get_bottom(), self.get_top()),
+        }
+    }
+
+    /// Corners in counter-clockwise order, starting from bottom left
+    pub fn get_vertices(&self) -> [Point2D; 4] {
+        let (left, right) = (self.get_left(), self.get_right());
+        let (bottom, top) = (self.get_bottom(), self.get_top());
+        [
+            Point2D { x: left, y: bottom },
+            Point2D {
+                x: right,
+                y: bottom,
+            },
+            Point2D { x: right, y: top },
+            Point2D { x: left, y: top },
+        ]
+    }
+
     pub fn get_segments(&self) -> [TypedAffine2D<Segment2D>; 4] {
         let horizontal_seg = Segment2D {
             angle: Angle::degrees(0.0),
@@ -0,0 +1,97 @@
+use crate::{angle::Angle, point::Point2D, vector::Vector2D};
+
+use super::{
+    Shape2D, Shape2DType,
+    affine::{EmbedInAffine2D, TypedAffine2D},
+    circle::Circle,
+    rectangle::Rectangle,
+};
+
+/// Rectangle with corners rounded by quarter circles of `radius`
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct RoundedRectangle {
+    pub width: f32,
+    pub height: f32,
+    pub radius: f32,
+}
+impl Shape2DType for RoundedRectangle {}
+
+impl From<RoundedRectangle> for Shape2D {
+    fn from(rounded_rect: RoundedRectangle) -> Self {
+        Shape2D::RoundedRectangle { rounded_rect }
+    }
+}
+
+impl TypedAffine2D<RoundedRectangle> {
+    /// Corner radius, never more than half of the shorter side
+    pub fn get_radius(&self) -> f32 {
+        self.shape
+            .radius
+            .min(self.shape.width.min(self.shape.height) / 2.0)
+            .max(0.0)
+    }
+
+    /// Rectangle spanned by the corner circle centers, the shape is every point within
+    /// the radius of it
+    pub fn get_core(&self) -> TypedAffine2D<Rectangle> {
+        let radius = self.get_radius();
+        Rectangle {
+            width: self.shape.width - 2.0 * radius,
+            height: self.shape.height - 2.0 * radius,
+        }
+        .embed_affine(&self.point)
+    }
+
+    /// Corner circles in counter-clockwise order, starting from bottom left
+    pub fn get_corner_circles(&self) -> [TypedAffine2D<Circle>; 4] {
+        let circle = Circle {
+            radius: self.get_radius(),
+        };
+        self.get_core()
+            .get_vertices()
+            .map(|center| circle.embed_affine(&center))
+    }
+
+    /// Full width and full height bands crossing at the center, together with the corner
+    /// circles they cover the shape exactly
+    pub fn get_bands(&self) -> [TypedAffine2D<Rectangle>; 2] {
+        let radius = self.get_radius();
+        [
+            Rectangle {
+                width: self.shape.width,
+                height: self.shape.height - 2.0 * radius,
+            }
+            .embed_affine(&self.point),
+            Rectangle {
+                width: self.shape.width - 2.0 * radius,
+                height: self.shape.height,
+            }
+            .embed_affine(&self.point),
+        ]
+    }
+
+    /// Point where the ray from the center at `angle` leaves the shape
+    pub fn get_boundary_point(&self, angle: &Angle) -> Point2D {
+        let direction = Vector2D::by_angle(angle);
+        let (half_width, half_height) = (self.shape.width / 2.0, self.shape.height / 2.0);
+        let t = (half_width / direction.x.abs()).min(half_height / direction.y.abs());
+        let radius = self.get_radius();
+        let (corner_x, corner_y) = (half_width - radius, half_height - radius);
+        let on_side = direction.clone() * t;
+        if on_side.x.abs() <= corner_x || on_side.y.abs() <= corner_y {
+            return self.point + on_side;
+        }
+        // the ray leaves through a corner arc, farther root of |t d - c| = radius
+        let center = Vector2D {
+            x: corner_x.copysign(direction.x),
+            y: corner_y.copysign(direction.y),
+        };
+        let along = direction.dot(&center);
+        let t = along
+            + (along * along - center.dot(&center) + radius * radius)
+                .max(0.0)
+                .sqrt();
+        self.point + direction * t
+    }
+}
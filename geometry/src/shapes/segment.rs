@@ -3,9 +3,15 @@ use super::{
     affine::{EmbedInAffine2D, TypedAffine2D},
     line::Line2D,
 };
-use crate::{angle::Angle, point::Point2D, vector::Vector2D};
+use crate::{
+    angle::Angle,
+    comparators::{are_equal, is_zero, less_or_equal},
+    point::{Point2D, dist},
+    vector::Vector2D,
+};
 
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Segment2D {
     pub angle: Angle,
     pub length: f32,
@@ -18,15 +24,36 @@ impl From<Segment2D> for Shape2D {
     }
 }
 
+/// Common part of two segments
+#[derive(Debug, Clone)]
+pub enum SegmentIntersection {
+    Point(Point2D),
+    /// Collinear segments overlapping on more than a single point
+    Segment(TypedAffine2D<Segment2D>),
+}
+
 impl Segment2D {
     pub fn get_line(&self) -> Line2D {
         Line2D {
             angle: self.angle.clone(),
         }
     }
+
+    pub fn get_direction(&self) -> Vector2D {
+        Vector2D::by_angle(&self.angle)
+    }
 }
 
 impl TypedAffine2D<Segment2D> {
+    pub fn from_points(start: &Point2D, end: &Point2D) -> TypedAffine2D<Segment2D> {
+        let v = *end - *start;
+        let segment = Segment2D {
+            angle: v.get_angle(),
+            length: v.length(),
+        };
+        segment.embed_affine(&(*start + v / 2.0))
+    }
+
     pub fn get_line(&self) -> TypedAffine2D<Line2D> {
         self.shape.get_line().embed_affine(&self.point)
     }
@@ -42,4 +69,94 @@ impl TypedAffine2D<Segment2D> {
         let v = Vector2D::by_angle(&self.shape.angle) * self.shape.length / 2.0;
         [self.point + v.clone(), self.point - v.clone()]
     }
+
+    pub fn get_start(&self) -> Point2D {
+        self.point - self.shape.get_direction() * self.shape.length / 2.0
+    }
+
+    pub fn get_end(&self) -> Point2D {
+        self.point + self.shape.get_direction() * self.shape.length / 2.0
+    }
+
+    pub fn get_vector(&self) -> Vector2D {
+        self.shape.get_direction() * self.shape.length
+    }
+
+    pub fn closest_point(&self, point: &Point2D) -> Point2D {
+        if is_zero(self.shape.length) {
+            return self.point;
+        }
+        let t = self.shape.get_direction().dot(&(*point - self.get_start())) / self.shape.length;
+        self.point_at(t.clamp(0.0, 1.0))
+    }
+
+    /// Point at `t` of the way from start to end
+    pub fn point_at(&self, t: f32) -> Point2D {
+        self.get_start() + self.get_vector() * t
+    }
+
+    pub fn segment_intersection(
+        &self,
+        other: &TypedAffine2D<Segment2D>,
+    ) -> Option<SegmentIntersection> {
+        if is_zero(self.shape.length) {
+            return other
+                .contains_point(&self.point)
+                .then_some(SegmentIntersection::Point(self.point));
+        }
+        if is_zero(other.shape.length) {
+            return self
+                .contains_point(&other.point)
+                .then_some(SegmentIntersection::Point(other.point));
+        }
+        let (r, s) = (self.get_vector(), other.get_vector());
+        let start_diff = other.get_start() - self.get_start();
+        let denom = r.cross(&s);
+        if is_zero(denom.abs() / (self.shape.length * other.shape.length)) {
+            return self.collinear_intersection(other, &start_diff);
+        }
+        let t = start_diff.cross(&s) / denom;
+        let u = start_diff.cross(&r) / denom;
+        let in_range = |v: f32| less_or_equal(0.0, v) && less_or_equal(v, 1.0);
+        (in_range(t) && in_range(u))
+            .then(|| SegmentIntersection::Point(self.point_at(t.clamp(0.0, 1.0))))
+    }
+
+    fn collinear_intersection(
+        &self,
+        other: &TypedAffine2D<Segment2D>,
+        start_diff: &Vector2D,
+    ) -> Option<SegmentIntersection> {
+        let direction = self.shape.get_direction();
+        if !is_zero(direction.cross(start_diff).abs()) {
+            return None;
+        }
+        let length = self.shape.length;
+        let t0 = direction.dot(start_diff) / length;
+        let t1 = t0 + direction.dot(&other.get_vector()) / length;
+        let from = f32::max(0.0, f32::min(t0, t1));
+        let to = f32::min(1.0, f32::max(t0, t1));
+        if !less_or_equal(from, to) {
+            return None;
+        }
+        let (start, end) = (self.point_at(from), self.point_at(to));
+        if are_equal(dist(&start, &end), 0.0) {
+            return Some(SegmentIntersection::Point(start));
+        }
+        Some(SegmentIntersection::Segment(Self::from_points(
+            &start, &end,
+        )))
+    }
+
+    fn contains_point(&self, point: &Point2D) -> bool {
+        let start_diff = *point - self.get_start();
+        if is_zero(self.shape.length) {
+            return is_zero(start_diff.length());
+        }
+        let direction = self.shape.get_direction();
+        let t = direction.dot(&start_diff);
+        is_zero(direction.cross(&start_diff).abs())
+            && less_or_equal(0.0, t)
+            && less_or_equal(t, self.shape.length)
+    }
 }
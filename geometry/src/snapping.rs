@@ -0,0 +1,312 @@
+use crate::{
+    bounding::BoundingBox,
+    comparators::are_equal,
+    point::Point2D,
+    shapes::{
+        affine::{Affine2D, TypedAffine2D},
+        rectangle::Rectangle,
+        segment::Segment2D,
+    },
+    vector::Vector2D,
+};
+
+#[derive(Debug, Clone)]
+pub struct SnapSettings {
+    /// Spacing of grid lines through the origin, no grid snapping when `None`
+    pub grid_size: Option<f32>,
+    /// Largest distance a shape is moved to snap
+    pub tolerance: f32,
+    /// Snap edges and centers to edges and centers of other shapes
+    pub align: bool,
+    /// Snap to positions repeating a gap between other shapes of the same row or column
+    pub equal_spacing: bool,
+}
+
+impl Default for SnapSettings {
+    fn default() -> Self {
+        SnapSettings {
+            grid_size: None,
+            tolerance: 5.0,
+            align: true,
+            equal_spacing: true,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GuideKind {
+    Grid,
+    Edge,
+    Center,
+    /// Gap between two shapes, all gaps of this kind in a snap are equal
+    Spacing,
+}
+
+/// Line to draw while a shape is snapped, in world space
+#[derive(Debug, Clone)]
+pub struct SnapGuide {
+    pub kind: GuideKind,
+    pub segment: TypedAffine2D<Segment2D>,
+}
+
+#[derive(Debug, Clone)]
+pub struct Snap {
+    /// Snapped position of the moving shape
+    pub point: Point2D,
+    pub guides: Vec<SnapGuide>,
+}
+
+/// Extents of a bounding box along one axis and across it
+#[derive(Debug, Clone, Copy)]
+struct AxisBox {
+    along: (f32, f32),
+    across: (f32, f32),
+}
+
+impl AxisBox {
+    fn new(b: &TypedAffine2D<Rectangle>, horizontal: bool) -> AxisBox {
+        let (x, y) = ((b.get_left(), b.get_right()), (b.get_bottom(), b.get_top()));
+        if horizontal {
+            AxisBox {
+                along: x,
+                across: y,
+            }
+        } else {
+            AxisBox {
+                along: y,
+                across: x,
+            }
+        }
+    }
+
+    fn center(&self) -> f32 {
+        (self.along.0 + self.along.1) / 2.0
+    }
+
+    fn shifted(&self, along: f32, across: f32) -> AxisBox {
+        AxisBox {
+            along: (self.along.0 + along, self.along.1 + along),
+            across: (self.across.0 + across, self.across.1 + across),
+        }
+    }
+
+    fn shares_row(&self, other: &AxisBox) -> bool {
+        self.across.0 <= other.across.1 && other.across.0 <= self.across.1
+    }
+}
+
+/// Guide with its end points as (along, across) pairs
+type AxisGuide = (GuideKind, (f32, f32), (f32, f32));
+
+/// What a candidate position lines up with, shapes are indices of the others and `None`
+/// stands for the moving shape
+#[derive(Debug, Clone)]
+enum Target {
+    Grid(f32),
+    Align(f32, usize, GuideKind),
+    Spacing(Vec<(Option<usize>, Option<usize>)>),
+}
+
+#[derive(Debug, Clone)]
+struct Candidate {
+    delta: f32,
+    target: Target,
+}
+
+/// Position for `moving` snapped to the grid, to edges and centers of `others` and to
+/// equal gaps between them, each axis is snapped on its own to the nearest candidate
+/// within the tolerance. Shapes are compared by their bounding boxes, unbounded shapes
+/// are ignored.
+pub fn snap(moving: &Affine2D, others: &[Affine2D], settings: &SnapSettings) -> Snap {
+    let Some(moving_box) = moving.bounding_box() else {
+        return Snap {
+            point: moving.point,
+            guides: vec![],
+        };
+    };
+    let boxes: Vec<Option<TypedAffine2D<Rectangle>>> =
+        others.iter().map(|o| o.bounding_box()).collect();
+    let [x, y] = [true, false].map(|horizontal| {
+        let m = AxisBox::new(&moving_box, horizontal);
+        let others: Vec<Option<AxisBox>> = boxes
+            .iter()
+            .map(|b| b.as_ref().map(|b| AxisBox::new(b, horizontal)))
+            .collect();
+        let candidates = snap_candidates(&m, &others, settings);
+        let best = candidates
+            .iter()
+            .map(|c| c.delta)
+            .min_by(|a, b| a.abs().total_cmp(&b.abs()));
+        match best {
+            Some(delta) => (
+                delta,
+                candidates
+                    .into_iter()
+                    .filter(|c| are_equal(c.delta, delta))
+                    .collect(),
+            ),
+            None => (0.0, vec![]),
+        }
+    });
+    let shift = Vector2D { x: x.0, y: y.0 };
+    let mut guides = vec![];
+    for (horizontal, (along, candidates), across) in [(true, x, shift.y), (false, y, shift.x)] {
+        let m = AxisBox::new(&moving_box, horizontal).shifted(along, across);
+        let others: Vec<Option<AxisBox>> = boxes
+            .iter()
+            .map(|b| b.as_ref().map(|b| AxisBox::new(b, horizontal)))
+            .collect();
+        let mut lines: Vec<(GuideKind, Point2D, Point2D)> = vec![];
+        for candidate in candidates {
+            for (kind, from, to) in candidate_guides(&candidate.target, &m, &others) {
+                let (from, to) = (to_point(from, horizontal), to_point(to, horizontal));
+                // several candidates may agree on the same guide
+                if !lines
+                    .iter()
+                    .any(|l| l.0 == kind && l.1 == from && l.2 == to)
+                {
+                    lines.push((kind, from, to));
+                }
+            }
+        }
+        guides.extend(lines.into_iter().map(|(kind, from, to)| SnapGuide {
+            kind,
+            segment: TypedAffine2D::<Segment2D>::from_points(&from, &to),
+        }));
+    }
+    Snap {
+        point: moving.point + shift,
+        guides,
+    }
+}
+
+fn to_point((along, across): (f32, f32), horizontal: bool) -> Point2D {
+    if horizontal {
+        Point2D {
+            x: along,
+            y: across,
+        }
+    } else {
+        Point2D {
+            x: across,
+            y: along,
+        }
+    }
+}
+
+fn snap_candidates(
+    m: &AxisBox,
+    others: &[Option<AxisBox>],
+    settings: &SnapSettings,
+) -> Vec<Candidate> {
+    let mut candidates = vec![];
+    let anchors = [m.along.0, m.center(), m.along.1];
+    if let Some(size) = settings.grid_size.filter(|s| *s > 0.0) {
+        for anchor in anchors {
+            let line = (anchor / size).round() * size;
+            candidates.push(Candidate {
+                delta: line - anchor,
+                target: Target::Grid(line),
+            });
+        }
+    }
+    if settings.align {
+        for (i, o) in others.iter().enumerate() {
+            let Some(o) = o else { continue };
+            for edge in [m.along.0, m.along.1] {
+                for target in [o.along.0, o.along.1] {
+                    candidates.push(Candidate {
+                        delta: target - edge,
+                        target: Target::Align(target, i, GuideKind::Edge),
+                    });
+                }
+            }
+            candidates.push(Candidate {
+                delta: o.center() - m.center(),
+                target: Target::Align(o.center(), i, GuideKind::Center),
+            });
+        }
+    }
+    if settings.equal_spacing {
+        candidates.extend(spacing_candidates(m, others));
+    }
+    candidates.retain(|c| c.delta.abs() <= settings.tolerance);
+    candidates
+}
+
+/// Positions repeating a gap between two neighbours of the row next to another shape of
+/// the row, and positions centered in the space between two neighbours
+fn spacing_candidates(m: &AxisBox, others: &[Option<AxisBox>]) -> Vec<Candidate> {
+    let mut row: Vec<(usize, AxisBox)> = others
+        .iter()
+        .enumerate()
+        .filter_map(|(i, o)| o.filter(|o| o.shares_row(m)).map(|o| (i, o)))
+        .collect();
+    row.sort_by(|a, b| a.1.along.0.total_cmp(&b.1.along.0));
+    let length = m.along.1 - m.along.0;
+    // repeated gaps are only useful where the shape does not cover a neighbour
+    let is_free = |delta: f32| {
+        let (start, end) = (m.along.0 + delta, m.along.1 + delta);
+        row.iter().all(|(_, o)| {
+            !(start < o.along.1 && o.along.0 < end)
+                || are_equal(start, o.along.1)
+                || are_equal(o.along.0, end)
+        })
+    };
+    let mut candidates = vec![];
+    // only gaps between neighbours are visible, wider ones span other shapes
+    for pair in row.windows(2) {
+        let [(a, a_box), (b, b_box)] = [pair[0], pair[1]];
+        let gap = b_box.along.0 - a_box.along.1;
+        if gap < 0.0 {
+            continue;
+        }
+        if gap >= length {
+            candidates.push(Candidate {
+                delta: (a_box.along.1 + b_box.along.0 - length) / 2.0 - m.along.0,
+                target: Target::Spacing(vec![(Some(a), None), (None, Some(b))]),
+            });
+        }
+        for &(c, c_box) in &row {
+            candidates.push(Candidate {
+                delta: c_box.along.1 + gap - m.along.0,
+                target: Target::Spacing(vec![(Some(a), Some(b)), (Some(c), None)]),
+            });
+            candidates.push(Candidate {
+                delta: c_box.along.0 - gap - m.along.1,
+                target: Target::Spacing(vec![(Some(a), Some(b)), (None, Some(c))]),
+            });
+        }
+    }
+    candidates.retain(|c| is_free(c.delta));
+    candidates
+}
+
+fn candidate_guides(target: &Target, m: &AxisBox, others: &[Option<AxisBox>]) -> Vec<AxisGuide> {
+    let get = |i: &Option<usize>| match i {
+        Some(i) => others[*i].expect("candidates only refer to bounded shapes"),
+        None => *m,
+    };
+    match target {
+        Target::Grid(line) => vec![(GuideKind::Grid, (*line, m.across.0), (*line, m.across.1))],
+        Target::Align(line, other, kind) => {
+            let o = get(&Some(*other));
+            let from = m.across.0.min(o.across.0);
+            let to = m.across.1.max(o.across.1);
+            vec![(*kind, (*line, from), (*line, to))]
+        }
+        Target::Spacing(pairs) => pairs
+            .iter()
+            .map(|(a, b)| {
+                let (a, b) = (get(a), get(b));
+                let (low, high) = (a.across.0.max(b.across.0), a.across.1.min(b.across.1));
+                let across = if low <= high {
+                    (low + high) / 2.0
+                } else {
+                    (a.across.0 + a.across.1 + b.across.0 + b.across.1) / 4.0
+                };
+                (GuideKind::Spacing, (a.along.1, across), (b.along.0, across))
+            })
+            .collect(),
+    }
+}
@@ -0,0 +1,516 @@
+use crate::{
+    angle::Angle,
+    bounding::{BoundingBox, bounding_box_of_points},
+    point::{Point2D, dist},
+    shapes::{
+        Shape2D,
+        affine::{Affine2D, EmbedInAffine2D, TypedAffine2D},
+        arc::EllipticalArc,
+        bezier::CubicBezier,
+        polygon::Polygon,
+        polyline::Polyline,
+        rectangle::Rectangle,
+    },
+    vector::Vector2D,
+};
+
+/// Piece of an SVG path in world coordinates with the y axis pointing up, the y axis of
+/// path data, which points down, is flipped when reading and writing it
+#[derive(Debug, Clone)]
+pub enum PathElement {
+    Polyline(Polyline),
+    /// Closed subpath made of straight lines only, vertices are relative to the origin
+    Polygon(Polygon),
+    Bezier(CubicBezier),
+    Arc(EllipticalArc),
+}
+
+/// Path data could not be read, `offset` is the byte offset of the unexpected input
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PathDataError {
+    pub offset: usize,
+}
+
+fn mirrored(p: &Point2D) -> Point2D {
+    Point2D { x: p.x, y: -p.y }
+}
+
+impl PathElement {
+    /// Element mirrored across the x axis, switching between world and SVG coordinates
+    fn mirrored(&self) -> PathElement {
+        match self {
+            PathElement::Polyline(polyline) => PathElement::Polyline(Polyline::new(
+                polyline.points.iter().map(mirrored).collect(),
+            )),
+            PathElement::Polygon(polygon) => PathElement::Polygon(Polygon::new(
+                polygon.vertices.iter().map(mirrored).collect(),
+            )),
+            PathElement::Bezier(bezier) => PathElement::Bezier(CubicBezier::new(
+                mirrored(&bezier.start),
+                mirrored(&bezier.control1),
+                mirrored(&bezier.control2),
+                mirrored(&bezier.end),
+            )),
+            PathElement::Arc(arc) => PathElement::Arc(EllipticalArc {
+                center: mirrored(&arc.center),
+                rotation: -arc.rotation.clone(),
+                start: -arc.start.clone(),
+                sweep: -arc.sweep.clone(),
+                ..arc.clone()
+            }),
+        }
+    }
+}
+
+struct Tokens<'a> {
+    data: &'a [u8],
+    offset: usize,
+}
+
+impl Tokens<'_> {
+    fn skip_separators(&mut self) {
+        while self
+            .data
+            .get(self.offset)
+            .is_some_and(|c| c.is_ascii_whitespace() || *c == b',')
+        {
+            self.offset += 1;
+        }
+    }
+
+    fn is_done(&mut self) -> bool {
+        self.skip_separators();
+        self.offset >= self.data.len()
+    }
+
+    fn command(&mut self) -> Option<u8> {
+        self.skip_separators();
+        let c = *self.data.get(self.offset)?;
+        if c.is_ascii_alphabetic() {
+            self.offset += 1;
+            return Some(c);
+        }
+        None
+    }
+
+    fn error(&self) -> PathDataError {
+        PathDataError {
+            offset: self.offset,
+        }
+    }
+
+    fn number(&mut self) -> Result<f32, PathDataError> {
+        self.skip_separators();
+        let start = self.offset;
+        let digits = |tokens: &mut Self| {
+            let from = tokens.offset;
+            while tokens
+                .data
+                .get(tokens.offset)
+                .is_some_and(u8::is_ascii_digit)
+            {
+                tokens.offset += 1;
+            }
+            tokens.offset - from
+        };
+        if matches!(self.data.get(self.offset), Some(b'+' | b'-')) {
+            self.offset += 1;
+        }
+        let mut mantissa = digits(self);
+        if self.data.get(self.offset) == Some(&b'.') {
+            self.offset += 1;
+            mantissa += digits(self);
+        }
+        if mantissa == 0 {
+            self.offset = start;
+            return Err(self.error());
+        }
+        if matches!(self.data.get(self.offset), Some(b'e' | b'E')) {
+            let before = self.offset;
+            self.offset += 1;
+            if matches!(self.data.get(self.offset), Some(b'+' | b'-')) {
+                self.offset += 1;
+            }
+            if digits(self) == 0 {
+                self.offset = before;
+            }
+        }
+        std::str::from_utf8(&self.data[start..self.offset])
+            .ok()
+            .and_then(|s| s.parse().ok())
+            .ok_or(PathDataError { offset: start })
+    }
+
+    /// Arc flags may be written without separators, as in `a1 1 0 01 1 1`
+    fn flag(&mut self) -> Result<bool, PathDataError> {
+        self.skip_separators();
+        let flag = match self.data.get(self.offset) {
+            Some(b'0') => false,
+            Some(b'1') => true,
+            _ => return Err(self.error()),
+        };
+        self.offset += 1;
+        Ok(flag)
+    }
+
+    fn point(&mut self) -> Result<Point2D, PathDataError> {
+        Ok(Point2D {
+            x: self.number()?,
+            y: self.number()?,
+        })
+    }
+}
+
+/// Collects elements while the path is drawn, straight lines are gathered into runs
+struct PathBuilder {
+    elements: Vec<PathElement>,
+    /// Straight run ending at the pen, empty or starting at the pen after a curve
+    run: Vec<Point2D>,
+    /// Index of the first element of the current subpath
+    subpath: usize,
+    start: Point2D,
+    pen: Point2D,
+}
+
+impl PathBuilder {
+    fn flush_run(&mut self) {
+        let run = std::mem::take(&mut self.run);
+        if run.len() >= 2 {
+            self.elements
+                .push(PathElement::Polyline(Polyline::new(run)));
+        }
+    }
+
+    fn move_to(&mut self, p: Point2D) {
+        self.flush_run();
+        self.start = p;
+        self.pen = p;
+        self.run = vec![p];
+        self.subpath = self.elements.len();
+    }
+
+    fn line_to(&mut self, p: Point2D) {
+        if self.run.is_empty() {
+            self.run.push(self.pen);
+        }
+        self.run.push(p);
+        self.pen = p;
+    }
+
+    fn curve(&mut self, element: PathElement, end: Point2D) {
+        self.flush_run();
+        self.elements.push(element);
+        self.pen = end;
+    }
+
+    fn close(&mut self) {
+        let closes = self.run.len() > 1 && self.run[self.run.len() - 1] == self.start;
+        let mut vertices = self.run.clone();
+        if closes {
+            vertices.pop();
+        }
+        if self.elements.len() == self.subpath && vertices.len() >= 3 {
+            self.run.clear();
+            self.elements
+                .push(PathElement::Polygon(Polygon::new(vertices)));
+        } else {
+            if self.pen != self.start {
+                self.line_to(self.start);
+            }
+            self.flush_run();
+        }
+        let start = self.start;
+        self.move_to(start);
+    }
+}
+
+/// Reads SVG path data (`M L H V C S Q T A Z` and their relative forms) into lines,
+/// curves and arcs in drawing order, closed subpaths of straight lines become polygons
+pub fn parse_path(data: &str) -> Result<Vec<PathElement>, PathDataError> {
+    let mut tokens = Tokens {
+        data: data.as_bytes(),
+        offset: 0,
+    };
+    let mut path = PathBuilder {
+        elements: vec![],
+        run: vec![],
+        subpath: 0,
+        start: Point2D::zero(),
+        pen: Point2D::zero(),
+    };
+    let mut command: Option<u8> = None;
+    // second control point of the previous curve, reflected by `S` and `T`
+    let mut cubic_control: Option<Point2D> = None;
+    let mut quadratic_control: Option<Point2D> = None;
+    while !tokens.is_done() {
+        let c = match tokens.command() {
+            Some(c) => c,
+            // numbers without a command repeat the last one, a move continues with lines
+            None => match command {
+                Some(b'M') => b'L',
+                Some(b'm') => b'l',
+                Some(b'Z' | b'z') | None => return Err(tokens.error()),
+                Some(c) => c,
+            },
+        };
+        command = Some(c);
+        let origin = if c.is_ascii_lowercase() {
+            path.pen - Point2D::zero()
+        } else {
+            Vector2D { x: 0.0, y: 0.0 }
+        };
+        let relative = |p: Point2D| p + origin.clone();
+        let (mut next_cubic, mut next_quadratic) = (None, None);
+        match c.to_ascii_uppercase() {
+            b'M' => path.move_to(relative(tokens.point()?)),
+            b'L' => path.line_to(relative(tokens.point()?)),
+            b'H' => {
+                let x = tokens.number()? + origin.x;
+                path.line_to(Point2D { x, y: path.pen.y });
+            }
+            b'V' => {
+                let y = tokens.number()? + origin.y;
+                path.line_to(Point2D { x: path.pen.x, y });
+            }
+            b'C' | b'S' => {
+                let control1 = if c.eq_ignore_ascii_case(&b'C') {
+                    relative(tokens.point()?)
+                } else {
+                    cubic_control.map_or(path.pen, |q| path.pen + (path.pen - q))
+                };
+                let control2 = relative(tokens.point()?);
+                let end = relative(tokens.point()?);
+                let bezier = CubicBezier::new(path.pen, control1, control2, end);
+                path.curve(PathElement::Bezier(bezier), end);
+                next_cubic = Some(control2);
+            }
+            b'Q' | b'T' => {
+                let control = if c.eq_ignore_ascii_case(&b'Q') {
+                    relative(tokens.point()?)
+                } else {
+                    quadratic_control.map_or(path.pen, |q| path.pen + (path.pen - q))
+                };
+                let end = relative(tokens.point()?);
+                let bezier = CubicBezier::from_quadratic(path.pen, control, end);
+                path.curve(PathElement::Bezier(bezier), end);
+                next_quadratic = Some(control);
+            }
+            b'A' => {
+                let (radius_x, radius_y) = (tokens.number()?, tokens.number()?);
+                let rotation = Angle::degrees(tokens.number()?);
+                let (large_arc, sweep) = (tokens.flag()?, tokens.flag()?);
+                let end = relative(tokens.point()?);
+                let arc = EllipticalArc::from_end_points(
+                    &path.pen, &end, radius_x, radius_y, &rotation, large_arc, sweep,
+                );
+                match arc {
+                    Some(arc) => path.curve(PathElement::Arc(arc), end),
+                    // arcs without radius are straight lines, arcs back to the pen vanish
+                    None if end != path.pen => path.line_to(end),
+                    None => {}
+                }
+            }
+            b'Z' => path.close(),
+            _ => {
+                tokens.offset -= 1;
+                return Err(tokens.error());
+            }
+        }
+        cubic_control = next_cubic;
+        quadratic_control = next_quadratic;
+    }
+    path.flush_run();
+    Ok(path.elements.iter().map(PathElement::mirrored).collect())
+}
+
+fn write_point(p: &Point2D) -> String {
+    format!("{} {}", p.x, p.y)
+}
+
+/// SVG path data drawing the elements in order, consecutive elements sharing an end
+/// point are joined into one subpath
+pub fn path_data(elements: &[PathElement]) -> String {
+    let mut parts: Vec<String> = vec![];
+    let mut pen: Option<Point2D> = None;
+    let move_to = |parts: &mut Vec<String>, pen: Option<Point2D>, p: Point2D| {
+        if pen != Some(p) {
+            parts.push(format!("M {}", write_point(&p)));
+        }
+    };
+    for element in elements.iter().map(PathElement::mirrored) {
+        match &element {
+            PathElement::Polyline(polyline) => {
+                let Some(first) = polyline.points.first() else {
+                    continue;
+                };
+                move_to(&mut parts, pen, *first);
+                for p in &polyline.points[1..] {
+                    parts.push(format!("L {}", write_point(p)));
+                }
+                pen = polyline.points.last().copied();
+            }
+            PathElement::Polygon(polygon) => {
+                let Some(first) = polygon.vertices.first() else {
+                    continue;
+                };
+                parts.push(format!("M {}", write_point(first)));
+                for p in &polygon.vertices[1..] {
+                    parts.push(format!("L {}", write_point(p)));
+                }
+                parts.push("Z".to_string());
+                pen = Some(*first);
+            }
+            PathElement::Bezier(bezier) => {
+                move_to(&mut parts, pen, bezier.start);
+                parts.push(format!(
+                    "C {} {} {}",
+                    write_point(&bezier.control1),
+                    write_point(&bezier.control2),
+                    write_point(&bezier.end)
+                ));
+                pen = Some(bezier.end);
+            }
+            PathElement::Arc(arc) => {
+                move_to(&mut parts, pen, arc.get_start());
+                // a single arc command cannot draw a full turn, large arcs are halved
+                let halves: &[f32] = if arc.is_large() { &[0.5, 1.0] } else { &[1.0] };
+                for t in halves {
+                    parts.push(format!(
+                        "A {} {} {} 0 {} {}",
+                        arc.radius_x,
+                        arc.radius_y,
+                        arc.rotation.as_degrees(),
+                        u8::from(arc.sweep.as_radians() > 0.0),
+                        write_point(&arc.point_at(*t))
+                    ));
+                }
+                pen = Some(arc.get_end());
+            }
+        }
+    }
+    parts.join(" ")
+}
+
+pub(crate) fn line_element(start: &Point2D, end: &Point2D) -> String {
+    format!(
+        r#"<line x1="{}" y1="{}" x2="{}" y2="{}"/>"#,
+        start.x, start.y, end.x, end.y
+    )
+}
+
+/// SVG element drawing the shape in world coordinates, `None` for lines, which have no
+/// end. Points are drawn as empty paths, visible with round line caps.
+pub fn to_svg_element(shape: &Affine2D) -> Option<String> {
+    let p = shape.point;
+    let element = match &shape.shape {
+        Shape2D::Point => format!(r#"<path d="M {} Z"/>"#, write_point(&p)),
+        Shape2D::Line { .. } => return None,
+        Shape2D::Segment { segment } => {
+            let [start, end] = segment.embed_affine(&p).get_end_points();
+            line_element(&start, &end)
+        }
+        Shape2D::Circle { circle } => {
+            format!(
+                r#"<circle cx="{}" cy="{}" r="{}"/>"#,
+                p.x, p.y, circle.radius
+            )
+        }
+        Shape2D::Rectangle { rect } => {
+            let rect = rect.embed_affine(&p);
+            format!(
+                r#"<rect x="{}" y="{}" width="{}" height="{}"/>"#,
+                rect.get_left(),
+                rect.get_bottom(),
+                rect.shape.width,
+                rect.shape.height
+            )
+        }
+        Shape2D::RoundedRectangle { rounded_rect } => {
+            let (width, height) = (rounded_rect.width, rounded_rect.height);
+            let radius = rounded_rect.embed_affine(&p).get_radius();
+            format!(
+                r#"<rect x="{}" y="{}" width="{width}" height="{height}" rx="{radius}" ry="{radius}"/>"#,
+                p.x - width / 2.0,
+                p.y - height / 2.0,
+            )
+        }
+        Shape2D::Polygon { polygon } => {
+            let points: Vec<String> = polygon
+                .embed_affine(&p)
+                .get_vertices()
+                .iter()
+                .map(|v| format!("{},{}", v.x, v.y))
+                .collect();
+            format!(r#"<polygon points="{}"/>"#, points.join(" "))
+        }
+    };
+    Some(element)
+}
+
+/// Standalone SVG image of the shapes with the world y axis pointing up, framed around
+/// all bounded shapes with `margin` around them. Lines are drawn across the frame.
+pub fn to_svg_document(shapes: &[Affine2D], margin: f32) -> String {
+    let frame = frame_of(shapes, &[], margin);
+    let mut content = vec![r#"<g fill="none" stroke="black" stroke-linecap="round">"#.to_string()];
+    content.extend(shapes.iter().map(|s| framed_element(s, &frame)));
+    content.push("</g>".to_string());
+    document(&frame, &content)
+}
+
+/// Bounding box of the bounded shapes, the anchors of unbounded ones and `points`,
+/// grown by `margin`
+pub(crate) fn frame_of(
+    shapes: &[Affine2D],
+    points: &[Point2D],
+    margin: f32,
+) -> TypedAffine2D<Rectangle> {
+    let mut corners: Vec<Point2D> = shapes
+        .iter()
+        .flat_map(|s| match s.bounding_box() {
+            Some(b) => b.get_vertices().to_vec(),
+            None => vec![s.point],
+        })
+        .collect();
+    corners.extend_from_slice(points);
+    bounding_box_of_points(&corners)
+        .unwrap_or_else(|| {
+            Rectangle {
+                width: 0.0,
+                height: 0.0,
+            }
+            .embed_affine(&Point2D::zero())
+        })
+        .inflated(margin)
+}
+
+/// Element of the shape, lines are drawn as segments reaching across `frame`
+pub(crate) fn framed_element(shape: &Affine2D, frame: &TypedAffine2D<Rectangle>) -> String {
+    match &shape.shape {
+        Shape2D::Line { line } => {
+            let [bottom_left, _, top_right, _] = frame.get_vertices();
+            let reach = dist(&shape.point, &frame.point) + dist(&bottom_left, &top_right);
+            let along = line.get_direction() * reach;
+            line_element(&(shape.point - along.clone()), &(shape.point + along))
+        }
+        _ => to_svg_element(shape).expect("only lines have no element"),
+    }
+}
+
+/// SVG document showing `frame`, `content` is given in world coordinates and drawn
+/// with the y axis pointing up
+pub(crate) fn document(frame: &TypedAffine2D<Rectangle>, content: &[String]) -> String {
+    let mut lines = vec![
+        format!(
+            r#"<svg xmlns="http://www.w3.org/2000/svg" viewBox="{} {} {} {}">"#,
+            frame.get_left(),
+            -frame.get_top(),
+            frame.shape.width,
+            frame.shape.height
+        ),
+        r#"<g transform="scale(1 -1)">"#.to_string(),
+    ];
+    lines.extend_from_slice(content);
+    lines.push("</g>".to_string());
+    lines.push("</svg>".to_string());
+    lines.join("\n")
+}
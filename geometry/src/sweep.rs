@@ -0,0 +1,448 @@
+use std::{
+    cmp::Ordering,
+    collections::{BTreeMap, HashMap, HashSet},
+};
+
+use crate::{
+    comparators::EPS,
+    point::Point2D,
+    shapes::{affine::TypedAffine2D, polyline::Polyline, segment::Segment2D},
+};
+
+/// Distance under which sweep coordinates are considered equal, segment end points are
+/// only as precise as the f32 angle and length they are computed from
+const SWEEP_EPS: f64 = EPS as f64;
+
+/// Intersection between two segments, given by their indices with the smaller one first
+#[derive(Debug, Clone)]
+pub struct SegmentCrossing {
+    pub point: Point2D,
+    pub segments: (usize, usize),
+}
+
+/// Intersection between two polylines, given by their indices with the smaller one first
+#[derive(Debug, Clone)]
+pub struct PolylineCrossing {
+    pub point: Point2D,
+    pub polylines: (usize, usize),
+}
+
+#[derive(Debug, Clone, Copy)]
+struct SweepPoint {
+    x: f64,
+    y: f64,
+}
+
+impl SweepPoint {
+    fn cmp(&self, other: &SweepPoint) -> Ordering {
+        self.x.total_cmp(&other.x).then(self.y.total_cmp(&other.y))
+    }
+
+    fn is_near(&self, other: &SweepPoint) -> bool {
+        (self.x - other.x).abs() <= SWEEP_EPS && (self.y - other.y).abs() <= SWEEP_EPS
+    }
+}
+
+impl From<Point2D> for SweepPoint {
+    fn from(p: Point2D) -> Self {
+        SweepPoint {
+            x: p.x as f64,
+            y: p.y as f64,
+        }
+    }
+}
+
+/// Events ordered left to right, then bottom to top
+#[derive(Debug, Clone, Copy)]
+struct EventKey(SweepPoint);
+
+impl PartialEq for EventKey {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for EventKey {}
+
+impl PartialOrd for EventKey {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for EventKey {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.0.cmp(&other.0)
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+struct SweepSegment {
+    left: SweepPoint,
+    right: SweepPoint,
+}
+
+impl SweepSegment {
+    fn is_vertical(&self) -> bool {
+        (self.right.x - self.left.x).abs() <= SWEEP_EPS
+    }
+
+    /// Height where the segment meets the sweep line at the event `p`, vertical segments
+    /// are taken at the event itself
+    fn y_at(&self, p: &SweepPoint) -> f64 {
+        if self.is_vertical() {
+            return p
+                .y
+                .clamp(self.left.y.min(self.right.y), self.left.y.max(self.right.y));
+        }
+        let t = (p.x - self.left.x) / (self.right.x - self.left.x);
+        self.left.y + t * (self.right.y - self.left.y)
+    }
+
+    fn slope(&self) -> f64 {
+        if self.is_vertical() {
+            f64::INFINITY
+        } else {
+            (self.right.y - self.left.y) / (self.right.x - self.left.x)
+        }
+    }
+
+    /// Single intersection point of two non parallel segments
+    fn intersection(&self, other: &SweepSegment) -> Option<SweepPoint> {
+        let (d1x, d1y) = (self.right.x - self.left.x, self.right.y - self.left.y);
+        let (d2x, d2y) = (other.right.x - other.left.x, other.right.y - other.left.y);
+        let denominator = d1x * d2y - d1y * d2x;
+        let (length1, length2) = (d1x.hypot(d1y), d2x.hypot(d2y));
+        if denominator.abs() <= f64::EPSILON * length1 * length2 {
+            return None;
+        }
+        let (ox, oy) = (other.left.x - self.left.x, other.left.y - self.left.y);
+        let t = (ox * d2y - oy * d2x) / denominator;
+        let u = (ox * d1y - oy * d1x) / denominator;
+        let within = |v: f64, length: f64| {
+            let tolerance = SWEEP_EPS / length.max(SWEEP_EPS);
+            (-tolerance..=1.0 + tolerance).contains(&v)
+        };
+        (within(t, length1) && within(u, length2)).then_some(SweepPoint {
+            x: self.left.x + t * d1x,
+            y: self.left.y + t * d1y,
+        })
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+struct StatusNode {
+    left: Option<usize>,
+    right: Option<usize>,
+    priority: u64,
+}
+
+/// Segments crossing the sweep line, bottom to top, kept in a treap whose nodes are
+/// indexed by segment, so that runs of segments are cut out and put back in O(log n)
+struct Status {
+    nodes: Vec<StatusNode>,
+    root: Option<usize>,
+}
+
+impl Status {
+    fn new(segment_count: usize) -> Status {
+        Status {
+            nodes: (0..segment_count as u64)
+                .map(|i| {
+                    // splitmix64, fixed priorities keep the sweep deterministic
+                    let mut z = (i + 1).wrapping_mul(0x9E37_79B9_7F4A_7C15);
+                    z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+                    z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+                    StatusNode {
+                        left: None,
+                        right: None,
+                        priority: z ^ (z >> 31),
+                    }
+                })
+                .collect(),
+            root: None,
+        }
+    }
+
+    /// Splits the tree into the leading segments where `below` holds and the rest
+    fn split(
+        &mut self,
+        tree: Option<usize>,
+        below: &impl Fn(usize) -> bool,
+    ) -> (Option<usize>, Option<usize>) {
+        let Some(t) = tree else {
+            return (None, None);
+        };
+        if below(t) {
+            let (left, right) = self.split(self.nodes[t].right, below);
+            self.nodes[t].right = left;
+            (Some(t), right)
+        } else {
+            let (left, right) = self.split(self.nodes[t].left, below);
+            self.nodes[t].left = right;
+            (left, Some(t))
+        }
+    }
+
+    /// Joins two trees, all segments of `low` are below those of `high`
+    fn merge(&mut self, low: Option<usize>, high: Option<usize>) -> Option<usize> {
+        match (low, high) {
+            (None, tree) | (tree, None) => tree,
+            (Some(a), Some(b)) => {
+                if self.nodes[a].priority > self.nodes[b].priority {
+                    self.nodes[a].right = self.merge(self.nodes[a].right, Some(b));
+                    Some(a)
+                } else {
+                    self.nodes[b].left = self.merge(Some(a), self.nodes[b].left);
+                    Some(b)
+                }
+            }
+        }
+    }
+
+    fn build(&mut self, segments: &[usize]) -> Option<usize> {
+        segments.iter().fold(None, |tree, &s| {
+            self.nodes[s].left = None;
+            self.nodes[s].right = None;
+            self.merge(tree, Some(s))
+        })
+    }
+
+    fn collect(&self, tree: Option<usize>, into: &mut Vec<usize>) {
+        if let Some(t) = tree {
+            self.collect(self.nodes[t].left, into);
+            into.push(t);
+            self.collect(self.nodes[t].right, into);
+        }
+    }
+
+    fn lowest(&self, mut tree: Option<usize>) -> Option<usize> {
+        while let Some(left) = tree.and_then(|t| self.nodes[t].left) {
+            tree = Some(left);
+        }
+        tree
+    }
+
+    fn highest(&self, mut tree: Option<usize>) -> Option<usize> {
+        while let Some(right) = tree.and_then(|t| self.nodes[t].right) {
+            tree = Some(right);
+        }
+        tree
+    }
+}
+
+struct Sweep {
+    segments: Vec<SweepSegment>,
+    /// Left end points of segments at every event
+    events: BTreeMap<EventKey, Vec<usize>>,
+    status: Status,
+    position: Option<SweepPoint>,
+    reported: HashSet<(usize, usize)>,
+    crossings: Vec<SegmentCrossing>,
+}
+
+impl Sweep {
+    /// Moves `p` onto the sweep line or an event column less than `SWEEP_EPS` away, so
+    /// that events at the same x are handled bottom to top
+    fn snap(&self, p: SweepPoint) -> SweepPoint {
+        let column = self
+            .position
+            .map(|current| current.x)
+            .filter(|x| (x - p.x).abs() <= SWEEP_EPS);
+        let x = column.or_else(|| {
+            self.events
+                .range(
+                    EventKey(SweepPoint {
+                        x: p.x - SWEEP_EPS,
+                        y: f64::NEG_INFINITY,
+                    })..=EventKey(SweepPoint {
+                        x: p.x + SWEEP_EPS,
+                        y: f64::INFINITY,
+                    }),
+                )
+                .next()
+                .map(|(key, _)| key.0.x)
+        });
+        SweepPoint {
+            x: x.unwrap_or(p.x),
+            y: p.y,
+        }
+    }
+
+    fn add_event(&mut self, p: SweepPoint, segment: Option<usize>) {
+        let p = self.snap(p);
+        let near = self
+            .events
+            .range(
+                EventKey(SweepPoint {
+                    x: p.x - SWEEP_EPS,
+                    y: f64::NEG_INFINITY,
+                })..=EventKey(SweepPoint {
+                    x: p.x + SWEEP_EPS,
+                    y: f64::INFINITY,
+                }),
+            )
+            .find(|(key, _)| key.0.is_near(&p))
+            .map(|(key, _)| *key);
+        let starting = self.events.entry(near.unwrap_or(EventKey(p))).or_default();
+        starting.extend(segment);
+    }
+
+    /// Queues the intersection of two neighbouring segments if it lies after `p`
+    fn check_pair(&mut self, a: usize, b: usize, p: &SweepPoint) {
+        if let Some(q) = self.segments[a].intersection(&self.segments[b])
+            && let q = self.snap(q)
+            && !q.is_near(p)
+            && q.cmp(p) == Ordering::Greater
+        {
+            self.add_event(q, None);
+        }
+    }
+
+    fn report(&mut self, a: usize, b: usize, p: &SweepPoint) {
+        let pair = (a.min(b), a.max(b));
+        if self.reported.insert(pair) {
+            self.crossings.push(SegmentCrossing {
+                point: Point2D {
+                    x: p.x as f32,
+                    y: p.y as f32,
+                },
+                segments: pair,
+            });
+        }
+    }
+
+    fn handle_event(&mut self, p: SweepPoint, starting: Vec<usize>) {
+        self.position = Some(p);
+        // segments through p are contiguous in the status
+        let segments = &self.segments;
+        let root = self.status.root.take();
+        let (lower, rest) = self
+            .status
+            .split(root, &|s| segments[s].y_at(&p) < p.y - SWEEP_EPS);
+        let (through_tree, upper) = self
+            .status
+            .split(rest, &|s| segments[s].y_at(&p) <= p.y + SWEEP_EPS);
+        let mut through = vec![];
+        self.status.collect(through_tree, &mut through);
+        let involved: Vec<usize> = through.iter().chain(&starting).copied().collect();
+        for (i, &a) in involved.iter().enumerate() {
+            for &b in &involved[i + 1..] {
+                self.report(a, b, &p);
+            }
+        }
+        // segments continuing after p are inserted in their order right of the sweep line
+        let mut continuing: Vec<usize> = involved
+            .into_iter()
+            .filter(|&s| !self.segments[s].right.is_near(&p))
+            .collect();
+        continuing.sort_by(|&a, &b| {
+            self.segments[a]
+                .slope()
+                .total_cmp(&self.segments[b].slope())
+        });
+        let below = self.status.highest(lower);
+        let above = self.status.lowest(upper);
+        match (continuing.first(), continuing.last()) {
+            (Some(&lowest), Some(&highest)) => {
+                if let Some(below) = below {
+                    self.check_pair(below, lowest, &p);
+                }
+                if let Some(above) = above {
+                    self.check_pair(highest, above, &p);
+                }
+            }
+            _ => {
+                if let (Some(below), Some(above)) = (below, above) {
+                    self.check_pair(below, above, &p);
+                }
+            }
+        }
+        let middle = self.status.build(&continuing);
+        let lower = self.status.merge(lower, middle);
+        self.status.root = self.status.merge(lower, upper);
+    }
+}
+
+/// All intersecting pairs of segments, touching and overlapping segments included
+/// (Bentley-Ottmann sweep line, O((n + k) log n) expected for k crossings)
+pub fn segment_crossings(segments: &[TypedAffine2D<Segment2D>]) -> Vec<SegmentCrossing> {
+    let mut sweep = Sweep {
+        segments: segments
+            .iter()
+            .map(|s| {
+                let (a, b) = (
+                    SweepPoint::from(s.get_start()),
+                    SweepPoint::from(s.get_end()),
+                );
+                let (mut left, mut right) = if a.cmp(&b) == Ordering::Greater {
+                    (b, a)
+                } else {
+                    (a, b)
+                };
+                // nearly vertical segments are made vertical and go bottom to top
+                if (right.x - left.x).abs() <= SWEEP_EPS {
+                    right.x = left.x;
+                    if left.y > right.y {
+                        std::mem::swap(&mut left, &mut right);
+                    }
+                }
+                SweepSegment { left, right }
+            })
+            .collect(),
+        events: BTreeMap::new(),
+        status: Status::new(segments.len()),
+        position: None,
+        reported: HashSet::new(),
+        crossings: vec![],
+    };
+    for i in 0..segments.len() {
+        sweep.add_event(sweep.segments[i].left, Some(i));
+        sweep.add_event(sweep.segments[i].right, None);
+    }
+    while let Some((EventKey(p), starting)) = sweep.events.pop_first() {
+        sweep.handle_event(p, starting);
+    }
+    sweep.crossings
+}
+
+/// Intersections between different polylines, polylines meeting only at a shared end point
+/// (links of the same node) are not reported there
+pub fn polyline_crossings(polylines: &[Polyline]) -> Vec<PolylineCrossing> {
+    let mut owners = vec![];
+    let mut segments = vec![];
+    for (i, polyline) in polylines.iter().enumerate() {
+        for segment in polyline.get_segments() {
+            owners.push(i);
+            segments.push(segment);
+        }
+    }
+    let ends = |i: usize| {
+        [polylines[i].points.first(), polylines[i].points.last()]
+            .into_iter()
+            .flatten()
+    };
+    let mut crossings: Vec<PolylineCrossing> = vec![];
+    let mut found: HashMap<(usize, usize), Vec<Point2D>> = HashMap::new();
+    for crossing in segment_crossings(&segments) {
+        let (a, b) = (owners[crossing.segments.0], owners[crossing.segments.1]);
+        if a == b {
+            continue;
+        }
+        let p = crossing.point;
+        if ends(a).any(|e| *e == p) && ends(b).any(|e| *e == p) {
+            continue;
+        }
+        let polylines = (a.min(b), a.max(b));
+        // crossings at a polyline vertex are found once for every adjacent segment
+        let points = found.entry(polylines).or_default();
+        if !points.contains(&p) {
+            points.push(p);
+            crossings.push(PolylineCrossing {
+                point: p,
+                polylines,
+            });
+        }
+    }
+    crossings
+}
@@ -0,0 +1,220 @@
+use crate::{
+    comparators::less_or_equal,
+    point::Point2D,
+    shapes::{
+        affine::TypedAffine2D,
+        polygon::{Polygon, PolygonWithHoles},
+    },
+};
+
+/// Triangle mesh ready to be uploaded as vertex and index buffers
+#[derive(Debug, Clone, Default)]
+pub struct TriangleMesh {
+    pub vertices: Vec<Point2D>,
+    /// Three indices into `vertices` per triangle, triangles are counter-clockwise
+    pub indices: Vec<u32>,
+}
+
+impl TriangleMesh {
+    pub fn triangle_count(&self) -> usize {
+        self.indices.len() / 3
+    }
+
+    pub fn get_triangle(&self, i: usize) -> [Point2D; 3] {
+        [0, 1, 2].map(|k| self.vertices[self.indices[3 * i + k] as usize])
+    }
+
+    /// Vertex coordinates flattened to `[x0, y0, x1, y1, ...]`
+    pub fn get_vertex_buffer(&self) -> Vec<f32> {
+        self.vertices.iter().flat_map(|v| [v.x, v.y]).collect()
+    }
+
+    /// Appends triangles of another mesh, shifting its indices
+    pub fn extend(&mut self, other: TriangleMesh) {
+        let offset = self.vertices.len() as u32;
+        self.vertices.extend(other.vertices);
+        self.indices
+            .extend(other.indices.iter().map(|i| i + offset));
+    }
+}
+
+impl From<&TypedAffine2D<Polygon>> for TriangleMesh {
+    fn from(polygon: &TypedAffine2D<Polygon>) -> Self {
+        triangulate(&PolygonWithHoles::new(
+            Polygon::new(polygon.get_vertices()),
+            vec![],
+        ))
+    }
+}
+
+/// Triangulates a simple polygon with holes by ear clipping, holes are first joined
+/// with the outer contour by bridge edges
+pub fn triangulate(polygon: &PolygonWithHoles) -> TriangleMesh {
+    let mut vertices: Vec<Point2D> = Vec::new();
+    let mut push_contour = |contour: &Polygon, ccw: bool| -> Vec<usize> {
+        let mut contour = contour.clone().oriented(ccw).vertices;
+        contour.dedup_by(|a, b| a == b);
+        while contour.len() > 1 && contour.first() == contour.last() {
+            contour.pop();
+        }
+        let start = vertices.len();
+        vertices.extend(contour);
+        (start..vertices.len()).collect()
+    };
+    let mut ring = push_contour(&polygon.outer, true);
+    let mut holes: Vec<Vec<usize>> = polygon
+        .holes
+        .iter()
+        .map(|h| push_contour(h, false))
+        .filter(|h| h.len() >= 3)
+        .collect();
+    if ring.len() < 3 {
+        return TriangleMesh::default();
+    }
+    // holes closest to the right side are bridged first, so later bridges cannot cross them
+    holes.sort_by(|h1, h2| {
+        let max_x = |h: &Vec<usize>| rightmost(&vertices, h).1;
+        max_x(h2).total_cmp(&max_x(h1))
+    });
+    for hole in holes {
+        bridge_hole(&vertices, &mut ring, &hole);
+    }
+    let indices = clip_ears(&vertices, ring);
+    TriangleMesh { vertices, indices }
+}
+
+fn cross(o: &Point2D, a: &Point2D, b: &Point2D) -> f32 {
+    (*a - *o).cross(&(*b - *o))
+}
+
+fn rightmost(vertices: &[Point2D], contour: &[usize]) -> (usize, f32) {
+    contour
+        .iter()
+        .enumerate()
+        .map(|(i, &v)| (i, vertices[v].x))
+        .max_by(|a, b| a.1.total_cmp(&b.1))
+        .expect("contour is not empty")
+}
+
+fn is_in_triangle(p: &Point2D, a: &Point2D, b: &Point2D, c: &Point2D) -> bool {
+    less_or_equal(0.0, cross(a, b, p))
+        && less_or_equal(0.0, cross(b, c, p))
+        && less_or_equal(0.0, cross(c, a, p))
+}
+
+/// Whether the diagonal from ring vertex `i` towards `p` starts inside the polygon
+fn is_locally_inside(vertices: &[Point2D], ring: &[usize], i: usize, p: &Point2D) -> bool {
+    let n = ring.len();
+    let (prev, v, next) = (
+        vertices[ring[(i + n - 1) % n]],
+        vertices[ring[i]],
+        vertices[ring[(i + 1) % n]],
+    );
+    if cross(&prev, &v, &next) >= 0.0 {
+        cross(&v, p, &next) <= 0.0 && cross(&v, &prev, p) <= 0.0
+    } else {
+        cross(&v, p, &prev) > 0.0 || cross(&v, &next, p) > 0.0
+    }
+}
+
+/// Connects hole to the ring with a pair of overlapping edges going from the rightmost
+/// hole vertex to a visible ring vertex
+fn bridge_hole(vertices: &[Point2D], ring: &mut Vec<usize>, hole: &[usize]) {
+    let (hole_start, _) = rightmost(vertices, hole);
+    let m = vertices[hole[hole_start]];
+    let n = ring.len();
+    // nearest upward ring edge hit by a ray going from m to the right
+    let mut best: Option<(f32, usize)> = None;
+    for i in 0..n {
+        let (a, b) = (vertices[ring[i]], vertices[ring[(i + 1) % n]]);
+        if !(a.y <= m.y && m.y <= b.y) || a.y == b.y {
+            continue;
+        }
+        let x = a.x + (m.y - a.y) / (b.y - a.y) * (b.x - a.x);
+        if x >= m.x && best.is_none_or(|(best_x, _)| x < best_x) {
+            let candidate = if a.x > b.x { i } else { (i + 1) % n };
+            best = Some((x, candidate));
+        }
+    }
+    let Some((hit_x, mut bridge)) = best else {
+        return;
+    };
+    // a reflex vertex inside the triangle between the ray and the candidate would block
+    // the bridge, the one closest in angle to the ray is visible
+    let hit = Point2D { x: hit_x, y: m.y };
+    let p = vertices[ring[bridge]];
+    let (tri_a, tri_c) = if cross(&m, &hit, &p) >= 0.0 {
+        (hit, p)
+    } else {
+        (p, hit)
+    };
+    let mut best_angle = f32::INFINITY;
+    for i in 0..n {
+        let v = vertices[ring[i]];
+        let (prev, next) = (vertices[ring[(i + n - 1) % n]], vertices[ring[(i + 1) % n]]);
+        let is_reflex = cross(&prev, &v, &next) < 0.0;
+        if v == p || !is_reflex || v.x < m.x || !is_in_triangle(&v, &m, &tri_a, &tri_c) {
+            continue;
+        }
+        let angle = f32::atan2((v.y - m.y).abs(), v.x - m.x);
+        if angle < best_angle && is_locally_inside(vertices, ring, i, &m) {
+            best_angle = angle;
+            bridge = i;
+        }
+    }
+    // earlier bridges duplicate vertices, pick the copy facing the hole
+    let target = vertices[ring[bridge]];
+    if let Some(i) =
+        (0..n).find(|&i| vertices[ring[i]] == target && is_locally_inside(vertices, ring, i, &m))
+    {
+        bridge = i;
+    }
+    let mut merged = ring[..=bridge].to_vec();
+    merged.extend(hole[hole_start..].iter());
+    merged.extend(hole[..=hole_start].iter());
+    merged.extend(ring[bridge..].iter());
+    *ring = merged;
+}
+
+fn clip_ears(vertices: &[Point2D], mut ring: Vec<usize>) -> Vec<u32> {
+    let mut indices = Vec::with_capacity(3 * ring.len());
+    let mut misses = 0;
+    let mut i = 0;
+    while ring.len() > 3 {
+        let n = ring.len();
+        let (prev, curr, next) = (ring[(i + n - 1) % n], ring[i % n], ring[(i + 1) % n]);
+        if is_ear(vertices, &ring, prev, curr, next) || misses > n {
+            // after a full round without ears the polygon is degenerate, cut anyway
+            if cross(&vertices[prev], &vertices[curr], &vertices[next]) > 0.0 {
+                indices.extend([prev as u32, curr as u32, next as u32]);
+            }
+            ring.remove(i % n);
+            misses = 0;
+            i %= ring.len();
+        } else {
+            misses += 1;
+            i = (i + 1) % n;
+        }
+    }
+    if ring.len() == 3 && cross(&vertices[ring[0]], &vertices[ring[1]], &vertices[ring[2]]) > 0.0 {
+        indices.extend(ring.iter().map(|&v| v as u32));
+    }
+    indices
+}
+
+fn is_ear(vertices: &[Point2D], ring: &[usize], prev: usize, curr: usize, next: usize) -> bool {
+    let (a, b, c) = (vertices[prev], vertices[curr], vertices[next]);
+    if cross(&a, &b, &c) <= 0.0 {
+        return false;
+    }
+    ring.iter().all(|&v| {
+        let p = vertices[v];
+        v == prev
+            || v == curr
+            || v == next
+            || p == a
+            || p == b
+            || p == c
+            || !is_in_triangle(&p, &a, &b, &c)
+    })
+}
@@ -2,6 +2,7 @@ use std::ops::{Add, Div, Mul, Neg, Sub};
 
 use crate::{angle::Angle, comparators::is_zero};
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Vector2D {
     pub x: f32,
     pub y: f32,
@@ -23,14 +24,21 @@ impl Vector2D {
         if is_zero(len) {
             return None;
         }
-        return Some(Vector2D {
+        Some(Vector2D {
             x: self.x / len,
             y: self.y / len,
-        });
+        })
     }
     pub fn get_angle(&self) -> Angle {
         Angle::atan2(self.x, self.y)
     }
+    pub fn dot(&self, other: &Vector2D) -> f32 {
+        self.x * other.x + self.y * other.y
+    }
+    /// Z coordinate of the 3D cross product, positive when `other` is counter-clockwise from `self`
+    pub fn cross(&self, other: &Vector2D) -> f32 {
+        self.x * other.y - self.y * other.x
+    }
 }
 // arithmetics
 impl Add for Vector2D {
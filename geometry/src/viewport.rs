@@ -0,0 +1,244 @@
+use crate::{
+    angle::Angle,
+    bounding::{OrientedRectangle, bounding_box_of_points},
+    comparators::{are_equal, is_zero},
+    point::Point2D,
+    shapes::{
+        Shape2D,
+        affine::{Affine2D, EmbedInAffine2D, TypedAffine2D},
+        circle::Circle,
+        line::Line2D,
+        polygon::Polygon,
+        rectangle::Rectangle,
+        rounded_rectangle::RoundedRectangle,
+        segment::Segment2D,
+    },
+    vector::Vector2D,
+};
+
+/// Points on every corner arc of rounded rectangles turned into polygons
+const ARC_SEGMENTS: usize = 8;
+
+/// Camera showing the world (y up) on a screen area (y down, origin top left)
+#[derive(Debug, Clone)]
+pub struct Viewport {
+    /// Size of the screen area in pixels
+    pub screen_width: f32,
+    pub screen_height: f32,
+    /// World point shown at the middle of the screen area
+    pub center: Point2D,
+    /// Screen pixels per world unit
+    pub zoom: f32,
+    /// Counter-clockwise rotation of the world as seen on screen
+    pub rotation: Angle,
+}
+
+impl Viewport {
+    pub fn new(screen_width: f32, screen_height: f32) -> Viewport {
+        Viewport {
+            screen_width,
+            screen_height,
+            center: Point2D::zero(),
+            zoom: 1.0,
+            rotation: Angle::degrees(0.0),
+        }
+    }
+
+    pub fn resize(&mut self, screen_width: f32, screen_height: f32) {
+        self.screen_width = screen_width;
+        self.screen_height = screen_height;
+    }
+
+    fn screen_center(&self) -> Point2D {
+        Point2D {
+            x: self.screen_width / 2.0,
+            y: self.screen_height / 2.0,
+        }
+    }
+
+    /// Rotation followed by flipping the y axis, its own inverse
+    fn reflect(&self, v: &Vector2D) -> Vector2D {
+        let (sin, cos) = (self.rotation.sin(), self.rotation.cos());
+        Vector2D {
+            x: cos * v.x - sin * v.y,
+            y: -(sin * v.x + cos * v.y),
+        }
+    }
+
+    pub fn world_to_screen_vector(&self, v: &Vector2D) -> Vector2D {
+        self.reflect(v) * self.zoom
+    }
+
+    pub fn screen_to_world_vector(&self, v: &Vector2D) -> Vector2D {
+        self.reflect(v) / self.zoom
+    }
+
+    pub fn world_to_screen(&self, p: &Point2D) -> Point2D {
+        self.screen_center() + self.world_to_screen_vector(&(*p - self.center))
+    }
+
+    pub fn screen_to_world(&self, p: &Point2D) -> Point2D {
+        self.center + self.screen_to_world_vector(&(*p - self.screen_center()))
+    }
+
+    /// Moves the world along with a pointer dragged by `screen_delta`
+    pub fn pan(&mut self, screen_delta: &Vector2D) {
+        self.center = self.center - self.screen_to_world_vector(screen_delta);
+    }
+
+    /// Multiplies the zoom by `factor`, the world point under `screen_pivot` stays in place
+    pub fn zoom_at(&mut self, factor: f32, screen_pivot: &Point2D) {
+        let pivot = self.screen_to_world(screen_pivot);
+        self.zoom *= factor;
+        self.center = self.center + (pivot - self.screen_to_world(screen_pivot));
+    }
+
+    /// Turns the world by `angle` on screen, the world point under `screen_pivot` stays in place
+    pub fn rotate_at(&mut self, angle: &Angle, screen_pivot: &Point2D) {
+        let pivot = self.screen_to_world(screen_pivot);
+        self.rotation = (self.rotation.clone() + angle.clone()).normalized();
+        self.center = self.center + (pivot - self.screen_to_world(screen_pivot));
+    }
+
+    /// Part of the world covered by the screen area, turned when the viewport is rotated
+    pub fn get_visible_area(&self) -> OrientedRectangle {
+        OrientedRectangle {
+            center: self.center,
+            rect: Rectangle {
+                width: self.screen_width / self.zoom,
+                height: self.screen_height / self.zoom,
+            },
+            angle: -self.rotation.clone(),
+        }
+    }
+
+    /// Axis aligned world rectangle containing everything visible on screen
+    pub fn get_visible_rect(&self) -> TypedAffine2D<Rectangle> {
+        bounding_box_of_points(&self.get_visible_area().get_vertices())
+            .expect("visible area has four corners")
+    }
+
+    /// Column-major matrix mapping homogeneous world points to screen pixels
+    pub fn world_to_screen_matrix(&self) -> [[f32; 3]; 3] {
+        let screen_center = self.screen_center();
+        self.matrix(1.0, 1.0, [screen_center.x, screen_center.y])
+    }
+
+    /// Column-major matrix mapping homogeneous world points to clip space, x and y in
+    /// [-1, 1] from the bottom left to the top right of the screen area
+    pub fn world_to_clip_matrix(&self) -> [[f32; 3]; 3] {
+        self.matrix(
+            2.0 / self.screen_width,
+            -2.0 / self.screen_height,
+            [0.0, 0.0],
+        )
+    }
+
+    /// World to screen transform scaled by `sx` and `sy` around the screen center, which
+    /// is moved to `origin`
+    fn matrix(&self, sx: f32, sy: f32, origin: [f32; 2]) -> [[f32; 3]; 3] {
+        let x_axis = self.world_to_screen_vector(&Vector2D { x: 1.0, y: 0.0 });
+        let y_axis = self.world_to_screen_vector(&Vector2D { x: 0.0, y: 1.0 });
+        let shift = self.world_to_screen_vector(&(Point2D::zero() - self.center));
+        [
+            [sx * x_axis.x, sy * x_axis.y, 0.0],
+            [sx * y_axis.x, sy * y_axis.y, 0.0],
+            [origin[0] + sx * shift.x, origin[1] + sy * shift.y, 1.0],
+        ]
+    }
+
+    pub fn world_to_screen_shape(&self, shape: &Affine2D) -> Affine2D {
+        self.map_shape(shape, self.world_to_screen(&shape.point), self.zoom)
+    }
+
+    pub fn screen_to_world_shape(&self, shape: &Affine2D) -> Affine2D {
+        self.map_shape(shape, self.screen_to_world(&shape.point), 1.0 / self.zoom)
+    }
+
+    /// Same shape seen from the other space, rectangles stay rectangles only while the
+    /// rotation is a multiple of 90 degrees and become polygons otherwise
+    fn map_shape(&self, shape: &Affine2D, point: Point2D, scale: f32) -> Affine2D {
+        // the mapping mirrors, both directions turn an angle `a` into `-a - rotation`
+        let map_angle = |angle: &Angle| (-angle.clone() - self.rotation.clone()).normalized();
+        let map_vector = |v: &Vector2D| self.reflect(v) * scale;
+        let quarter_turns = self.rotation.normalized().as_degrees() / 90.0;
+        let axis_aligned = are_equal(quarter_turns, quarter_turns.round());
+        let swapped = axis_aligned && quarter_turns.round() as i32 % 2 == 1;
+        let polygon = |vertices: Vec<Point2D>| Polygon {
+            vertices: vertices
+                .iter()
+                .map(|v| Point2D::zero() + map_vector(&(*v - shape.point)))
+                .collect(),
+        };
+        let mapped: Shape2D = match &shape.shape {
+            Shape2D::Point => Shape2D::Point,
+            Shape2D::Line { line } => Line2D {
+                angle: map_angle(&line.angle),
+            }
+            .into(),
+            Shape2D::Segment { segment } => Segment2D {
+                angle: map_angle(&segment.angle),
+                length: segment.length * scale,
+            }
+            .into(),
+            Shape2D::Circle { circle } => Circle {
+                radius: circle.radius * scale,
+            }
+            .into(),
+            Shape2D::Polygon { polygon: p } => {
+                polygon(p.embed_affine(&shape.point).get_vertices()).into()
+            }
+            Shape2D::Rectangle { rect } if axis_aligned => {
+                let (width, height) = if swapped {
+                    (rect.height, rect.width)
+                } else {
+                    (rect.width, rect.height)
+                };
+                Rectangle {
+                    width: width * scale,
+                    height: height * scale,
+                }
+                .into()
+            }
+            Shape2D::Rectangle { rect } => {
+                polygon(rect.embed_affine(&shape.point).get_vertices().to_vec()).into()
+            }
+            Shape2D::RoundedRectangle { rounded_rect } if axis_aligned => {
+                let (width, height) = if swapped {
+                    (rounded_rect.height, rounded_rect.width)
+                } else {
+                    (rounded_rect.width, rounded_rect.height)
+                };
+                RoundedRectangle {
+                    width: width * scale,
+                    height: height * scale,
+                    radius: rounded_rect.radius * scale,
+                }
+                .into()
+            }
+            Shape2D::RoundedRectangle { rounded_rect } => polygon(rounded_rect_outline(
+                &rounded_rect.embed_affine(&shape.point),
+            ))
+            .into(),
+        };
+        mapped.embed_affine(&point)
+    }
+}
+
+fn rounded_rect_outline(rr: &TypedAffine2D<RoundedRectangle>) -> Vec<Point2D> {
+    let radius = rr.get_radius();
+    if is_zero(radius) {
+        return rr.get_core().get_vertices().to_vec();
+    }
+    // corners are listed from bottom left, the bottom left arc starts pointing left
+    rr.get_corner_circles()
+        .iter()
+        .enumerate()
+        .flat_map(|(k, c)| {
+            (0..=ARC_SEGMENTS).map(move |i| {
+                let degrees = 180.0 + 90.0 * (k as f32 + i as f32 / ARC_SEGMENTS as f32);
+                c.point + Vector2D::by_angle(&Angle::degrees(degrees)) * c.shape.radius
+            })
+        })
+        .collect()
+}
@@ -0,0 +1,109 @@
+use geometry::angle::Angle;
+
+mod angle_arithmetics {
+    use super::*;
+    #[test]
+    fn test_add_mixed_representations() {
+        let sum = Angle::degrees(90.0) + Angle::radians(std::f32::consts::FRAC_PI_2);
+        assert!(sum == Angle::degrees(180.0));
+        assert!(sum.as_degrees() > 179.99 && sum.as_degrees() < 180.01);
+    }
+
+    #[test]
+    fn test_sub_neg_mul() {
+        assert!(Angle::degrees(30.0) - Angle::degrees(50.0) == Angle::degrees(-20.0));
+        assert!(-Angle::degrees(45.0) == Angle::degrees(-45.0));
+        assert!(Angle::degrees(45.0) * 2.0 == Angle::degrees(90.0));
+        assert!(0.5 * Angle::degrees(45.0) == Angle::degrees(22.5));
+    }
+}
+
+mod angle_comparison {
+    use super::*;
+    #[test]
+    fn test_equal_in_different_representations() {
+        assert!(Angle::degrees(180.0) == Angle::radians(std::f32::consts::PI));
+    }
+
+    #[test]
+    fn test_full_turn_is_not_equal_to_zero() {
+        assert!(Angle::degrees(360.0) != Angle::degrees(0.0));
+        assert!(Angle::degrees(360.0).normalized() == Angle::degrees(0.0));
+    }
+
+    #[test]
+    fn test_ordering() {
+        assert!(Angle::degrees(10.0) < Angle::degrees(20.0));
+        assert!(Angle::radians(1.0) > Angle::degrees(45.0));
+        assert!(Angle::degrees(45.0) <= Angle::radians(std::f32::consts::FRAC_PI_4));
+    }
+}
+
+mod angle_wrapping {
+    use super::*;
+    #[test]
+    fn test_normalized_signed() {
+        assert!(Angle::degrees(270.0).normalized_signed() == Angle::degrees(-90.0));
+        assert!(Angle::degrees(-180.0).normalized_signed() == Angle::degrees(180.0));
+        assert!(Angle::degrees(540.0).normalized_signed() == Angle::degrees(180.0));
+        assert!(Angle::degrees(-30.0).normalized_signed() == Angle::degrees(-30.0));
+    }
+
+    #[test]
+    fn test_normalized_keeps_representation() {
+        let angle = Angle::radians(-std::f32::consts::FRAC_PI_2).normalized();
+        assert!(matches!(
+            angle.representation(),
+            geometry::angle::AngleRepresentation::Radians
+        ));
+        assert!(angle == Angle::degrees(270.0));
+
+        let angle = Angle::degrees(-90.0).normalized();
+        assert!(matches!(
+            angle.representation(),
+            geometry::angle::AngleRepresentation::Degrees
+        ));
+        assert_eq!(angle.as_degrees(), 270.0);
+    }
+
+    #[test]
+    fn test_normalized_signed_keeps_representation() {
+        let angle = Angle::radians(3.0 * std::f32::consts::FRAC_PI_2).normalized_signed();
+        assert!(matches!(
+            angle.representation(),
+            geometry::angle::AngleRepresentation::Radians
+        ));
+        assert!(angle == Angle::radians(-std::f32::consts::FRAC_PI_2));
+
+        let angle = Angle::degrees(270.0).normalized_signed();
+        assert!(matches!(
+            angle.representation(),
+            geometry::angle::AngleRepresentation::Degrees
+        ));
+        assert_eq!(angle.as_degrees(), -90.0);
+    }
+
+    #[test]
+    fn test_shortest_difference() {
+        let from = Angle::degrees(350.0);
+        assert!(from.shortest_difference(&Angle::degrees(10.0)) == Angle::degrees(20.0));
+        assert!(Angle::degrees(10.0).shortest_difference(&from) == Angle::degrees(-20.0));
+    }
+}
+
+mod angle_interpolation {
+    use super::*;
+    #[test]
+    fn test_lerp() {
+        let angle = Angle::degrees(350.0).lerp(&Angle::degrees(10.0), 0.5);
+        assert!(angle == Angle::degrees(180.0));
+    }
+
+    #[test]
+    fn test_slerp_takes_shorter_arc() {
+        let angle = Angle::degrees(350.0).slerp(&Angle::degrees(10.0), 0.5);
+        assert!(angle.normalized() == Angle::degrees(0.0));
+        let angle = Angle::degrees(350.0).slerp(&Angle::degrees(10.0), 0.25);
+        assert!(angle.normalized() == Angle::degrees(355.0));
+    }
+}
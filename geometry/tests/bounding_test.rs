@@ -0,0 +1,191 @@
+use geometry::{
+    angle::Angle,
+    bounding::{
+        BoundingBox, bounding_box_of_shapes, convex_hull, convex_hull_of_shapes, min_area_rect,
+        min_enclosing_circle,
+    },
+    containment::Contains,
+    point::Point2D,
+    shapes::{
+        affine::{Affine2D, EmbedInAffine2D},
+        circle::Circle,
+        polygon::Polygon,
+        rectangle::Rectangle,
+        rounded_rectangle::RoundedRectangle,
+    },
+};
+
+fn assert_close(a: f32, b: f32) {
+    assert!((a - b).abs() < 0.001, "{a} != {b}");
+}
+
+fn p(x: f32, y: f32) -> Point2D {
+    Point2D { x, y }
+}
+
+mod convex_hull_of_points {
+    use super::*;
+    #[test]
+    fn test_square_with_inner_and_collinear_points() {
+        let points = [
+            p(0.0, 0.0),
+            p(1.0, 1.0),
+            p(2.0, 0.0),
+            p(1.0, 0.0),
+            p(2.0, 2.0),
+            p(0.0, 2.0),
+            p(0.5, 1.5),
+        ];
+        let hull = convex_hull(&points);
+        assert_eq!(hull.len(), 4);
+        assert!(hull[0] == p(0.0, 0.0));
+        assert!(hull[1] == p(2.0, 0.0));
+        assert!(hull[2] == p(2.0, 2.0));
+        assert!(hull[3] == p(0.0, 2.0));
+    }
+
+    #[test]
+    fn test_degenerate_input() {
+        assert!(convex_hull(&[]).is_empty());
+        assert_eq!(convex_hull(&[p(1.0, 1.0), p(1.0, 1.0)]).len(), 1);
+        assert_eq!(
+            convex_hull(&[p(0.0, 0.0), p(1.0, 1.0), p(2.0, 2.0)]).len(),
+            2
+        );
+    }
+}
+
+mod convex_hull_of_shape_set {
+    use super::*;
+    #[test]
+    fn test_hull_contains_all_shapes() {
+        let shapes = [
+            Affine2D::new(
+                0.0,
+                0.0,
+                Rectangle {
+                    width: 2.0,
+                    height: 2.0,
+                }
+                .into(),
+            ),
+            Affine2D::new(5.0, 1.0, Circle { radius: 1.0 }.into()),
+        ];
+        let hull = convex_hull_of_shapes(&shapes, 16).unwrap();
+        let hull = Polygon::new(hull).embed_affine(&Point2D::zero());
+        for shape in &shapes {
+            assert!(hull.contains(shape).unwrap());
+        }
+    }
+}
+
+mod bounding_boxes {
+    use super::*;
+    #[test]
+    fn test_bounding_box_of_shapes() {
+        let shapes = [
+            Affine2D::new(
+                0.0,
+                0.0,
+                Rectangle {
+                    width: 2.0,
+                    height: 2.0,
+                }
+                .into(),
+            ),
+            Affine2D::new(5.0, 1.0, Circle { radius: 1.0 }.into()),
+        ];
+        let bbox = bounding_box_of_shapes(&shapes).unwrap();
+        assert_close(bbox.get_left(), -1.0);
+        assert_close(bbox.get_right(), 6.0);
+        assert_close(bbox.get_bottom(), -1.0);
+        assert_close(bbox.get_top(), 2.0);
+    }
+
+    #[test]
+    fn test_rounded_rectangle_bounds() {
+        let card = RoundedRectangle {
+            width: 4.0,
+            height: 2.0,
+            radius: 0.5,
+        }
+        .embed_affine(&p(1.0, 1.0));
+        let bbox = card.bounding_box().unwrap();
+        assert!(bbox.point == p(1.0, 1.0));
+        assert_close(bbox.shape.width, 4.0);
+        assert_close(bbox.shape.height, 2.0);
+        let right = card.get_boundary_point(&Angle::degrees(0.0));
+        assert!(right == p(3.0, 1.0));
+        let top = card.get_boundary_point(&Angle::degrees(90.0));
+        assert!(top == p(1.0, 2.0));
+        // the ray towards the bounding box corner leaves through the rounded corner
+        let corner = card.get_boundary_point(&(p(3.0, 2.0) - p(1.0, 1.0)).get_angle());
+        assert!(corner.x < 3.0 && corner.y < 2.0);
+        assert_close((corner - p(2.5, 1.5)).length(), 0.5);
+        let hull = convex_hull_of_shapes(&[card.into()], 8).unwrap();
+        assert!(hull.len() >= 8);
+    }
+
+    #[test]
+    fn test_polygon_bounding_box() {
+        let triangle =
+            Polygon::new(vec![p(0.0, 0.0), p(4.0, 0.0), p(0.0, 2.0)]).embed_affine(&p(1.0, 1.0));
+        let bbox = triangle.bounding_box().unwrap();
+        assert!(bbox.point == p(3.0, 2.0));
+        assert_close(bbox.shape.width, 4.0);
+        assert_close(bbox.shape.height, 2.0);
+    }
+}
+
+mod minimum_shapes {
+    use super::*;
+    #[test]
+    fn test_min_area_rect_of_rotated_square() {
+        let points = [p(0.0, 1.0), p(1.0, 0.0), p(2.0, 1.0), p(1.0, 2.0)];
+        let rect = min_area_rect(&points).unwrap();
+        assert_close(rect.rect.width * rect.rect.height, 2.0);
+        assert!(rect.center == p(1.0, 1.0));
+        let rect_polygon = rect.to_polygon();
+        for point in &points {
+            assert!(rect_polygon.contains(point).unwrap());
+        }
+    }
+
+    #[test]
+    fn test_min_enclosing_circle() {
+        let points = [p(0.0, 0.0), p(2.0, 0.0), p(1.0, 0.5), p(1.0, -0.5)];
+        let circle = min_enclosing_circle(&points).unwrap();
+        assert!(circle.point == p(1.0, 0.0));
+        assert_close(circle.shape.radius, 1.0);
+    }
+
+    #[test]
+    fn test_min_enclosing_circle_of_triangle() {
+        let points = [p(0.0, 0.0), p(2.0, 0.0), p(1.0, 1.5)];
+        let circle = min_enclosing_circle(&points).unwrap();
+        for point in &points {
+            assert_close(
+                geometry::point::dist(&circle.point, point),
+                circle.shape.radius,
+            );
+        }
+    }
+
+    #[test]
+    fn test_min_enclosing_circle_of_many_hull_points() {
+        // points on a circle, all of them on the hull
+        let points: Vec<Point2D> = (0..500)
+            .map(|i| {
+                let a = i as f32 / 500.0 * std::f32::consts::TAU;
+                p(3.0 + 10.0 * a.cos(), -2.0 + 10.0 * a.sin())
+            })
+            .collect();
+        let circle = min_enclosing_circle(&points).unwrap();
+        assert!(circle.point == p(3.0, -2.0));
+        assert_close(circle.shape.radius, 10.0);
+        // the shuffle has a fixed seed, results do not change between calls
+        let again = min_enclosing_circle(&points).unwrap();
+        assert!(again.point.x == circle.point.x && again.point.y == circle.point.y);
+        assert!(again.shape.radius == circle.shape.radius);
+    }
+}
@@ -0,0 +1,128 @@
+use geometry::{
+    clipping::{difference, intersection, union, xor},
+    point::Point2D,
+    shapes::polygon::{Polygon, PolygonWithHoles},
+};
+
+fn assert_close(a: f32, b: f32) {
+    assert!((a - b).abs() < 0.001, "{a} != {b}");
+}
+
+fn square(x: f32, y: f32, size: f32) -> PolygonWithHoles {
+    PolygonWithHoles::new(
+        Polygon::new(vec![
+            Point2D { x, y },
+            Point2D { x: x + size, y },
+            Point2D {
+                x: x + size,
+                y: y + size,
+            },
+            Point2D { x, y: y + size },
+        ]),
+        vec![],
+    )
+}
+
+fn total_area(region: &[PolygonWithHoles]) -> f32 {
+    region.iter().map(|p| p.area()).sum()
+}
+
+mod overlapping_squares {
+    use super::*;
+    #[test]
+    fn test_union() {
+        let result = union(&[square(0.0, 0.0, 2.0)], &[square(1.0, 1.0, 2.0)]);
+        assert_eq!(result.len(), 1);
+        assert_eq!(result[0].outer.vertices.len(), 8);
+        assert_close(total_area(&result), 7.0);
+    }
+
+    #[test]
+    fn test_intersection() {
+        let result = intersection(&[square(0.0, 0.0, 2.0)], &[square(1.0, 1.0, 2.0)]);
+        assert_eq!(result.len(), 1);
+        assert_eq!(result[0].outer.vertices.len(), 4);
+        assert_close(total_area(&result), 1.0);
+    }
+
+    #[test]
+    fn test_difference() {
+        let result = difference(&[square(0.0, 0.0, 2.0)], &[square(1.0, 1.0, 2.0)]);
+        assert_eq!(result.len(), 1);
+        assert_eq!(result[0].outer.vertices.len(), 6);
+        assert_close(total_area(&result), 3.0);
+    }
+
+    #[test]
+    fn test_xor() {
+        let result = xor(&[square(0.0, 0.0, 2.0)], &[square(1.0, 1.0, 2.0)]);
+        assert_close(total_area(&result), 6.0);
+    }
+}
+
+mod holes_and_multiple_contours {
+    use super::*;
+    #[test]
+    fn test_difference_creates_hole() {
+        let result = difference(&[square(0.0, 0.0, 4.0)], &[square(1.0, 1.0, 2.0)]);
+        assert_eq!(result.len(), 1);
+        assert_eq!(result[0].holes.len(), 1);
+        assert!(!result[0].holes[0].is_counter_clockwise());
+        assert_close(total_area(&result), 12.0);
+    }
+
+    #[test]
+    fn test_intersection_with_holed_polygon() {
+        let frame = difference(&[square(0.0, 0.0, 4.0)], &[square(1.0, 1.0, 2.0)]);
+        let result = intersection(&frame, &[square(2.0, -1.0, 4.0)]);
+        assert_close(total_area(&result), 6.0 - 2.0);
+    }
+
+    #[test]
+    fn test_cut_into_two_pieces() {
+        let bar = PolygonWithHoles::new(
+            Polygon::new(vec![
+                Point2D { x: 1.0, y: -1.0 },
+                Point2D { x: 2.0, y: -1.0 },
+                Point2D { x: 2.0, y: 4.0 },
+                Point2D { x: 1.0, y: 4.0 },
+            ]),
+            vec![],
+        );
+        let result = difference(&[square(0.0, 0.0, 3.0)], &[bar]);
+        assert_eq!(result.len(), 2);
+        assert_close(total_area(&result), 6.0);
+    }
+
+    #[test]
+    fn test_disjoint_union() {
+        let result = union(&[square(0.0, 0.0, 1.0)], &[square(2.0, 0.0, 1.0)]);
+        assert_eq!(result.len(), 2);
+        assert_close(total_area(&result), 2.0);
+    }
+}
+
+mod shared_edges {
+    use super::*;
+    #[test]
+    fn test_union_of_adjacent_squares() {
+        let result = union(&[square(0.0, 0.0, 1.0)], &[square(1.0, 0.0, 1.0)]);
+        assert_eq!(result.len(), 1);
+        assert_eq!(result[0].outer.vertices.len(), 4);
+        assert_close(total_area(&result), 2.0);
+    }
+
+    #[test]
+    fn test_intersection_of_adjacent_squares_is_empty() {
+        let result = intersection(&[square(0.0, 0.0, 1.0)], &[square(1.0, 0.0, 1.0)]);
+        assert_close(total_area(&result), 0.0);
+    }
+
+    #[test]
+    fn test_identical_squares() {
+        let a = [square(0.0, 0.0, 1.0)];
+        assert_close(total_area(&union(&a, &a)), 1.0);
+        assert_close(total_area(&intersection(&a, &a)), 1.0);
+        assert_close(total_area(&difference(&a, &a)), 0.0);
+    }
+}
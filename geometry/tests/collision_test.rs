@@ -6,7 +6,9 @@ use geometry::{
         affine::{Affine2D, EmbedInAffine2D, TypedAffine2D},
         circle::Circle,
         line::Line2D,
+        polygon::Polygon,
         rectangle::Rectangle,
+        rounded_rectangle::RoundedRectangle,
         segment::Segment2D,
     },
     vector::Vector2D,
@@ -19,6 +21,15 @@ fn rect_w2_h2() -> Rectangle {
     }
 }
 
+/// 4 x 2 card with corners rounded by 1, only its left and right ends are round
+fn card() -> RoundedRectangle {
+    RoundedRectangle {
+        width: 4.0,
+        height: 2.0,
+        radius: 1.0,
+    }
+}
+
 fn circle_r1() -> Circle {
     Circle { radius: 1.0 }
 }
@@ -29,6 +40,14 @@ fn line_deg(deg: f32) -> Line2D {
     }
 }
 
+fn triangle() -> Polygon {
+    Polygon::new(vec![
+        Point2D { x: 0.0, y: 0.0 },
+        Point2D { x: 4.0, y: 0.0 },
+        Point2D { x: 0.0, y: 4.0 },
+    ])
+}
+
 fn segment_deg(deg: f32, len: f32) -> Segment2D {
     Segment2D {
         angle: Angle::degrees(deg),
@@ -542,3 +561,305 @@ mod circle_rectange_collision {
         assert!(r.check_collision(&c).unwrap());
     }
 }
+
+mod polygon_collision {
+    use super::*;
+    #[test]
+    fn test_point_inside_and_on_edge() {
+        let aff = Affine2D::new(1.0, 1.0, triangle().into());
+        assert!(aff.check_collision(&Point2D { x: 2.0, y: 2.0 }).unwrap());
+        assert!(aff.check_collision(&Point2D { x: 3.0, y: 3.0 }).unwrap());
+        assert!(!aff.check_collision(&Point2D { x: 3.1, y: 3.1 }).unwrap());
+    }
+
+    #[test]
+    fn test_segment_inside() {
+        let aff = Affine2D::new(0.0, 0.0, triangle().into());
+        let segment = Affine2D::new(1.0, 1.0, segment_deg(0.0, 1.0).into());
+        assert!(aff.check_collision(&segment).unwrap());
+        assert!(segment.check_collision(&aff).unwrap());
+    }
+
+    #[test]
+    fn test_line() {
+        let aff = Affine2D::new(0.0, 0.0, triangle().into());
+        let crossing = Affine2D::new(0.0, 2.0, line_deg(0.0).into());
+        let outside = Affine2D::new(0.0, 5.0, line_deg(-45.0).into());
+        assert!(aff.check_collision(&crossing).unwrap());
+        assert!(!aff.check_collision(&outside).unwrap());
+    }
+
+    #[test]
+    fn test_circle() {
+        let aff = Affine2D::new(0.0, 0.0, triangle().into());
+        let touching = Affine2D::new(-1.0, 1.0, circle_r1().into());
+        let outside = Affine2D::new(3.0, 3.0, circle_r1().into());
+        assert!(aff.check_collision(&touching).unwrap());
+        assert!(!aff.check_collision(&outside).unwrap());
+    }
+
+    #[test]
+    fn test_rectangle() {
+        let aff = Affine2D::new(0.0, 0.0, triangle().into());
+        let containing = Affine2D::new(
+            2.0,
+            2.0,
+            Rectangle {
+                width: 10.0,
+                height: 10.0,
+            }
+            .into(),
+        );
+        let outside = Affine2D::new(4.0, 4.0, rect_w2_h2().into());
+        assert!(aff.check_collision(&containing).unwrap());
+        assert!(containing.check_collision(&aff).unwrap());
+        assert!(!aff.check_collision(&outside).unwrap());
+    }
+
+    #[test]
+    fn test_polygon() {
+        let p1 = Affine2D::new(0.0, 0.0, triangle().into());
+        let p2 = Affine2D::new(1.0, 1.0, triangle().into());
+        let p3 = Affine2D::new(3.0, 3.0, triangle().into());
+        assert!(p1.check_collision(&p2).unwrap());
+        assert!(!p1.check_collision(&p3).unwrap());
+    }
+}
+
+mod rounded_rectangle_collision {
+    use super::*;
+
+    fn rounded_w4_h4_r1() -> TypedAffine2D<RoundedRectangle> {
+        RoundedRectangle {
+            width: 4.0,
+            height: 4.0,
+            radius: 1.0,
+        }
+        .embed_affine(&Point2D::zero())
+    }
+
+    #[test]
+    fn test_point_in_transparent_corner() {
+        let rr = rounded_w4_h4_r1();
+        assert!(rr.check_collision(&Point2D { x: 0.0, y: 2.0 }).unwrap());
+        assert!(rr.check_collision(&Point2D { x: 1.5, y: 1.5 }).unwrap());
+        assert!(!rr.check_collision(&Point2D { x: 1.9, y: 1.9 }).unwrap());
+        let on_arc = 1.0 + std::f32::consts::FRAC_1_SQRT_2;
+        assert!(
+            rr.check_collision(&Point2D {
+                x: on_arc,
+                y: on_arc
+            })
+            .unwrap()
+        );
+    }
+
+    #[test]
+    fn test_circle() {
+        let rr = rounded_w4_h4_r1();
+        let diagonal_touching = 1.0 + 2.0 * std::f32::consts::FRAC_1_SQRT_2;
+        let touching = circle_r1().embed_affine(&Point2D {
+            x: diagonal_touching,
+            y: diagonal_touching,
+        });
+        let in_corner = circle_r1().embed_affine(&Point2D { x: 2.9, y: 2.9 });
+        let beside = circle_r1().embed_affine(&Point2D { x: 3.0, y: 0.0 });
+        assert!(rr.check_collision(&touching).unwrap());
+        assert!(!rr.check_collision(&in_corner).unwrap());
+        assert!(rr.check_collision(&beside).unwrap());
+    }
+
+    #[test]
+    fn test_segment() {
+        let rr = rounded_w4_h4_r1();
+        let across_corner = segment_deg(-45.0, 2.0).embed_affine(&Point2D { x: 1.9, y: 1.9 });
+        let through_corner = segment_deg(-45.0, 2.0).embed_affine(&Point2D { x: 1.5, y: 1.5 });
+        let inside = segment_deg(30.0, 1.0).embed_affine(&Point2D::zero());
+        let steep = segment_deg(89.0, 10.0).embed_affine(&Point2D { x: 1.0, y: 0.0 });
+        assert!(!rr.check_collision(&across_corner).unwrap());
+        assert!(rr.check_collision(&through_corner).unwrap());
+        assert!(rr.check_collision(&inside).unwrap());
+        assert!(rr.check_collision(&steep).unwrap());
+    }
+
+    #[test]
+    fn test_line() {
+        let rr = rounded_w4_h4_r1();
+        let past_corner = line_deg(-45.0).embed_affine(&Point2D { x: 1.9, y: 1.9 });
+        let tangent = line_deg(0.0).embed_affine(&Point2D { x: 5.0, y: 2.0 });
+        assert!(!rr.check_collision(&past_corner).unwrap());
+        assert!(rr.check_collision(&tangent).unwrap());
+    }
+
+    #[test]
+    fn test_rectangles() {
+        let rr = rounded_w4_h4_r1();
+        let corner_to_corner = rect_w2_h2().embed_affine(&Point2D { x: 2.9, y: 2.9 });
+        let side_by_side = rect_w2_h2().embed_affine(&Point2D { x: 3.0, y: 0.5 });
+        assert!(!rr.check_collision(&corner_to_corner).unwrap());
+        assert!(!corner_to_corner.check_collision(&rr).unwrap());
+        assert!(rr.check_collision(&side_by_side).unwrap());
+        let corners_apart = card().embed_affine(&Point2D { x: 3.5, y: 3.0 });
+        let touching = card().embed_affine(&Point2D { x: 4.0, y: 1.0 });
+        assert!(!rr.check_collision(&corners_apart).unwrap());
+        assert!(rr.check_collision(&touching).unwrap());
+        assert!(touching.check_collision(&rr).unwrap());
+    }
+
+    #[test]
+    fn test_polygon() {
+        let rr = rounded_w4_h4_r1();
+        let far = Affine2D::new(1.9, 1.9, triangle().into());
+        let near = Affine2D::new(1.5, 1.5, triangle().into());
+        assert!(!rr.check_collision(&far).unwrap());
+        assert!(rr.check_collision(&near).unwrap());
+        let big = Polygon::new(vec![
+            Point2D { x: -10.0, y: -10.0 },
+            Point2D { x: 10.0, y: -10.0 },
+            Point2D { x: 0.0, y: 10.0 },
+        ])
+        .embed_affine(&Point2D::zero());
+        assert!(rr.check_collision(&big).unwrap());
+    }
+}
+
+mod batch_collision {
+    use super::*;
+    use geometry::collisions::batch::{
+        CircleSet, RectangleSet, check_collision_many, query_point_many,
+    };
+
+    fn grid_circles() -> Vec<TypedAffine2D<Circle>> {
+        (0..20)
+            .map(|i| {
+                Circle {
+                    radius: 0.5 + (i % 3) as f32 * 0.25,
+                }
+                .embed_affine(&Point2D {
+                    x: (i % 5) as f32 * 1.5,
+                    y: (i / 5) as f32 * 1.5,
+                })
+            })
+            .collect()
+    }
+
+    fn grid_rects() -> Vec<TypedAffine2D<Rectangle>> {
+        (0..20)
+            .map(|i| {
+                Rectangle {
+                    width: 1.0 + (i % 2) as f32,
+                    height: 1.0,
+                }
+                .embed_affine(&Point2D {
+                    x: (i % 5) as f32 * 2.0,
+                    y: (i / 5) as f32 * 2.0,
+                })
+            })
+            .collect()
+    }
+
+    fn scalar_hits<T: Collisions<S>, S>(shapes: &[T], query: &S) -> Vec<usize> {
+        (0..shapes.len())
+            .filter(|i| shapes[*i].check_collision(query).unwrap())
+            .collect()
+    }
+
+    #[test]
+    fn test_query_point_many() {
+        let shapes: Vec<Affine2D> = vec![
+            Affine2D::new(0.0, 0.0, Circle { radius: 1.0 }.into()),
+            Affine2D::new(5.0, 0.0, rect_w2_h2().into()),
+            Affine2D::new(1.0, 0.0, card().into()),
+            Affine2D::new(
+                10.0,
+                0.0,
+                Polygon::new(vec![
+                    Point2D { x: -1.0, y: -1.0 },
+                    Point2D { x: 1.0, y: -1.0 },
+                    Point2D { x: 0.0, y: 1.0 },
+                ])
+                .into(),
+            ),
+        ];
+        assert_eq!(
+            query_point_many(&shapes, &Point2D { x: 0.5, y: 0.0 }).unwrap(),
+            vec![0, 2]
+        );
+        assert!(
+            query_point_many(&shapes, &Point2D { x: 3.0, y: 3.0 })
+                .unwrap()
+                .is_empty()
+        );
+        assert_eq!(
+            query_point_many(&shapes, &Point2D { x: 10.0, y: 0.5 }).unwrap(),
+            vec![3]
+        );
+        let probe = Affine2D::new(4.0, 0.0, Circle { radius: 0.5 }.into());
+        assert_eq!(check_collision_many(&shapes, &probe).unwrap(), vec![1]);
+        let probe = Affine2D::new(9.5, 1.0, Circle { radius: 0.5 }.into());
+        assert_eq!(check_collision_many(&shapes, &probe).unwrap(), vec![3]);
+    }
+
+    #[test]
+    fn test_circle_set_matches_scalar_checks() {
+        let circles = grid_circles();
+        let set: CircleSet = circles.iter().collect();
+        assert_eq!(set.len(), circles.len());
+        assert!(set.get(7).unwrap().point == circles[7].point);
+        for k in 0..12 {
+            let point = Point2D {
+                x: k as f32 * 0.6 - 0.5,
+                y: k as f32 * 0.45,
+            };
+            assert_eq!(set.query_point(&point), scalar_hits(&circles, &point));
+            let circle = Circle { radius: 0.3 }.embed_affine(&point);
+            assert_eq!(set.query_circle(&circle), scalar_hits(&circles, &circle));
+            let rect = rect_w2_h2().embed_affine(&point);
+            assert_eq!(set.query_rect(&rect), scalar_hits(&circles, &rect));
+        }
+    }
+
+    #[test]
+    fn test_rectangle_set_matches_scalar_checks() {
+        let rects = grid_rects();
+        let set: RectangleSet = rects.iter().collect();
+        assert_eq!(set.get(3).unwrap().shape.width, rects[3].shape.width);
+        for k in 0..12 {
+            let point = Point2D {
+                x: k as f32 * 0.7 - 0.5,
+                y: k as f32 * 0.55,
+            };
+            assert_eq!(set.query_point(&point), scalar_hits(&rects, &point));
+            let circle = Circle { radius: 0.4 }.embed_affine(&point);
+            assert_eq!(set.query_circle(&circle), scalar_hits(&rects, &circle));
+            let rect = rect_w2_h2().embed_affine(&point);
+            assert_eq!(set.query_rect(&rect), scalar_hits(&rects, &rect));
+        }
+        // corners count as collisions
+        let corner = Point2D { x: 0.5, y: 0.5 };
+        assert_eq!(set.query_point(&corner), vec![0]);
+    }
+
+    #[test]
+    fn test_rectangle_set_point_near_edge_matches_query_point_many() {
+        let rects = grid_rects();
+        let set: RectangleSet = rects.iter().collect();
+        let shapes: Vec<Affine2D> = rects
+            .iter()
+            .map(|r| Affine2D::new(r.point.x, r.point.y, r.shape.clone().into()))
+            .collect();
+        for point in [
+            Point2D { x: 0.50005, y: 0.0 },
+            Point2D {
+                x: 0.0,
+                y: -0.50005,
+            },
+            Point2D { x: 0.5002, y: 0.0 },
+        ] {
+            assert_eq!(
+                set.query_point(&point),
+                query_point_many(&shapes, &point).unwrap()
+            );
+        }
+    }
+}
@@ -0,0 +1,265 @@
+use geometry::{
+    angle::Angle,
+    containment::Contains,
+    point::Point2D,
+    shapes::{
+        affine::Affine2D, circle::Circle, line::Line2D, polygon::Polygon, rectangle::Rectangle,
+        rounded_rectangle::RoundedRectangle, segment::Segment2D,
+    },
+};
+
+fn rect(width: f32, height: f32) -> Rectangle {
+    Rectangle { width, height }
+}
+
+fn rounded(width: f32, height: f32, radius: f32) -> RoundedRectangle {
+    RoundedRectangle {
+        width,
+        height,
+        radius,
+    }
+}
+
+fn circle(radius: f32) -> Circle {
+    Circle { radius }
+}
+
+fn segment_deg(deg: f32, len: f32) -> Segment2D {
+    Segment2D {
+        angle: Angle::degrees(deg),
+        length: len,
+    }
+}
+
+/// U shaped polygon, the notch spans x in (1, 3) and y in (1, 4)
+fn u_shape() -> Polygon {
+    Polygon::new(vec![
+        Point2D { x: 0.0, y: 0.0 },
+        Point2D { x: 4.0, y: 0.0 },
+        Point2D { x: 4.0, y: 4.0 },
+        Point2D { x: 3.0, y: 4.0 },
+        Point2D { x: 3.0, y: 1.0 },
+        Point2D { x: 1.0, y: 1.0 },
+        Point2D { x: 1.0, y: 4.0 },
+        Point2D { x: 0.0, y: 4.0 },
+    ])
+}
+
+mod rectangle_contains {
+    use super::*;
+    #[test]
+    fn test_rect_contains_circle() {
+        let r = Affine2D::new(0.0, 0.0, rect(4.0, 4.0).into());
+        assert!(
+            r.contains(&Affine2D::new(1.0, 1.0, circle(1.0).into()))
+                .unwrap()
+        );
+        assert!(
+            !r.contains(&Affine2D::new(1.5, 1.0, circle(1.0).into()))
+                .unwrap()
+        );
+    }
+
+    #[test]
+    fn test_rect_contains_rect() {
+        let r = Affine2D::new(0.0, 0.0, rect(4.0, 4.0).into());
+        let inner = Affine2D::new(1.0, 1.0, rect(2.0, 2.0).into());
+        let crossing = Affine2D::new(2.0, 1.0, rect(2.0, 2.0).into());
+        assert!(r.contains(&inner).unwrap());
+        assert!(!r.contains(&crossing).unwrap());
+        assert!(!inner.contains(&r).unwrap());
+    }
+
+    #[test]
+    fn test_rect_does_not_contain_line() {
+        let r = Affine2D::new(0.0, 0.0, rect(4.0, 4.0).into());
+        let l = Affine2D::new(
+            0.0,
+            0.0,
+            Line2D {
+                angle: Angle::degrees(0.0),
+            }
+            .into(),
+        );
+        assert!(!r.contains(&l).unwrap());
+    }
+}
+
+mod circle_contains {
+    use super::*;
+    #[test]
+    fn test_circle_contains_segment() {
+        let c = Affine2D::new(0.0, 0.0, circle(2.0).into());
+        assert!(
+            c.contains(&Affine2D::new(0.0, 0.0, segment_deg(45.0, 4.0).into()))
+                .unwrap()
+        );
+        assert!(
+            !c.contains(&Affine2D::new(0.5, 0.0, segment_deg(0.0, 4.0).into()))
+                .unwrap()
+        );
+    }
+
+    #[test]
+    fn test_circle_contains_circle() {
+        let c = Affine2D::new(0.0, 0.0, circle(2.0).into());
+        assert!(
+            c.contains(&Affine2D::new(1.0, 0.0, circle(1.0).into()))
+                .unwrap()
+        );
+        assert!(
+            !c.contains(&Affine2D::new(1.1, 0.0, circle(1.0).into()))
+                .unwrap()
+        );
+    }
+
+    #[test]
+    fn test_circle_contains_rect() {
+        let c = Affine2D::new(0.0, 0.0, circle(f32::sqrt(2.0)).into());
+        assert!(
+            c.contains(&Affine2D::new(0.0, 0.0, rect(2.0, 2.0).into()))
+                .unwrap()
+        );
+        assert!(
+            !c.contains(&Affine2D::new(0.0, 0.0, rect(2.2, 2.0).into()))
+                .unwrap()
+        );
+    }
+}
+
+mod segment_contains {
+    use super::*;
+    #[test]
+    fn test_segment_contains_segment() {
+        let s = Affine2D::new(0.0, 0.0, segment_deg(0.0, 4.0).into());
+        assert!(
+            s.contains(&Affine2D::new(1.0, 0.0, segment_deg(180.0, 2.0).into()))
+                .unwrap()
+        );
+        assert!(
+            !s.contains(&Affine2D::new(1.5, 0.0, segment_deg(0.0, 2.0).into()))
+                .unwrap()
+        );
+        assert!(s.contains(&Point2D { x: -2.0, y: 0.0 }).unwrap());
+    }
+}
+
+mod polygon_contains {
+    use super::*;
+    #[test]
+    fn test_concave_polygon_contains_rect() {
+        let p = Affine2D::new(0.0, 0.0, u_shape().into());
+        let in_leg = Affine2D::new(0.5, 2.0, rect(1.0, 4.0).into());
+        let across_notch = Affine2D::new(2.0, 3.0, rect(3.0, 0.5).into());
+        assert!(p.contains(&in_leg).unwrap());
+        assert!(!p.contains(&across_notch).unwrap());
+    }
+
+    #[test]
+    fn test_concave_polygon_segment_across_notch() {
+        let p = Affine2D::new(0.0, 0.0, u_shape().into());
+        // both ends lie inside the legs, but the middle passes through the notch
+        let s = Affine2D::new(2.0, 3.0, segment_deg(0.0, 3.0).into());
+        assert!(!p.contains(&s).unwrap());
+        let s = Affine2D::new(2.0, 0.5, segment_deg(0.0, 3.0).into());
+        assert!(p.contains(&s).unwrap());
+    }
+
+    #[test]
+    fn test_polygon_contains_circle() {
+        let p = Affine2D::new(0.0, 0.0, u_shape().into());
+        assert!(
+            p.contains(&Affine2D::new(2.0, 0.5, circle(0.5).into()))
+                .unwrap()
+        );
+        assert!(
+            !p.contains(&Affine2D::new(2.0, 0.5, circle(0.6).into()))
+                .unwrap()
+        );
+    }
+
+    #[test]
+    fn test_rect_contains_polygon() {
+        let r = Affine2D::new(2.0, 2.0, rect(4.0, 4.0).into());
+        let p = Affine2D::new(0.0, 0.0, u_shape().into());
+        assert!(r.contains(&p).unwrap());
+        assert!(!p.contains(&r).unwrap());
+    }
+}
+
+mod rounded_rectangle_contains {
+    use super::*;
+    #[test]
+    fn test_rounded_rect_contains_rect() {
+        let rr = Affine2D::new(0.0, 0.0, rounded(4.0, 4.0, 1.0).into());
+        assert!(
+            rr.contains(&Affine2D::new(0.0, 0.0, rect(2.0, 2.0).into()))
+                .unwrap()
+        );
+        assert!(
+            !rr.contains(&Affine2D::new(0.0, 0.0, rect(4.0, 4.0).into()))
+                .unwrap()
+        );
+        assert!(
+            rr.contains(&Affine2D::new(0.0, 0.0, rect(4.0, 2.0).into()))
+                .unwrap()
+        );
+    }
+
+    #[test]
+    fn test_rounded_rect_contains_circle() {
+        let rr = Affine2D::new(0.0, 0.0, rounded(4.0, 4.0, 1.0).into());
+        assert!(
+            rr.contains(&Affine2D::new(0.0, 0.0, circle(2.0).into()))
+                .unwrap()
+        );
+        assert!(
+            rr.contains(&Affine2D::new(1.0, 1.0, circle(1.0).into()))
+                .unwrap()
+        );
+        assert!(
+            !rr.contains(&Affine2D::new(1.1, 1.1, circle(1.0).into()))
+                .unwrap()
+        );
+        assert!(
+            !rr.contains(&Affine2D::new(0.1, 0.0, circle(2.0).into()))
+                .unwrap()
+        );
+    }
+
+    #[test]
+    fn test_shapes_containing_rounded_rect() {
+        let rr = Affine2D::new(0.0, 0.0, rounded(4.0, 4.0, 1.0).into());
+        let enclosing = 1.0 + std::f32::consts::SQRT_2;
+        assert!(
+            Affine2D::new(0.0, 0.0, rect(4.0, 4.0).into())
+                .contains(&rr)
+                .unwrap()
+        );
+        assert!(
+            !Affine2D::new(0.0, 0.0, rect(4.0, 3.9).into())
+                .contains(&rr)
+                .unwrap()
+        );
+        assert!(
+            Affine2D::new(0.0, 0.0, circle(enclosing).into())
+                .contains(&rr)
+                .unwrap()
+        );
+        assert!(
+            !Affine2D::new(0.0, 0.0, circle(2.2).into())
+                .contains(&rr)
+                .unwrap()
+        );
+    }
+
+    #[test]
+    fn test_rounded_rect_contains_rounded_rect() {
+        let round = Affine2D::new(0.0, 0.0, rounded(4.0, 4.0, 2.0).into());
+        let small = Affine2D::new(0.0, 0.0, rounded(2.0, 2.0, 1.0).into());
+        let card = Affine2D::new(0.0, 0.0, rounded(4.0, 4.0, 1.0).into());
+        assert!(round.contains(&small).unwrap());
+        assert!(!round.contains(&card).unwrap());
+        assert!(card.contains(&round).unwrap());
+    }
+}
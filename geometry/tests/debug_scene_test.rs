@@ -0,0 +1,65 @@
+use geometry::{
+    debug_scene::DebugScene,
+    point::Point2D,
+    shapes::{affine::Affine2D, circle::Circle, rectangle::Rectangle},
+    vector::Vector2D,
+};
+
+fn p(x: f32, y: f32) -> Point2D {
+    Point2D { x, y }
+}
+
+mod recording {
+    use super::*;
+    #[test]
+    fn test_tangent_circles_are_hit() {
+        let mut scene = DebugScene::new();
+        let a = scene.add_shape(Affine2D::new(0.0, 0.0, Circle { radius: 1.0 }.into()));
+        let b = scene.add_shape(Affine2D::new(2.0, 0.0, Circle { radius: 1.0 }.into()));
+        let c = scene.add_shape(Affine2D::new(
+            10.0,
+            10.0,
+            Rectangle {
+                width: 2.0,
+                height: 2.0,
+            }
+            .into(),
+        ));
+        assert!(scene.record_collision(a, b).unwrap());
+        assert!(!scene.record_collision(a, c).unwrap());
+        assert_eq!(scene.hits, vec![a, b]);
+        assert_eq!(scene.record_point_query(&p(10.5, 10.5)).unwrap(), vec![c]);
+        assert_eq!(scene.hits, vec![a, b, c]);
+    }
+}
+
+mod output {
+    use super::*;
+    #[test]
+    fn test_svg_contains_all_records() {
+        let mut scene = DebugScene::new();
+        scene.add_shape(Affine2D::new(0.0, 0.0, Circle { radius: 1.0 }.into()));
+        scene.add_shape(Affine2D::new(2.0, 0.0, Circle { radius: 1.0 }.into()));
+        scene.mark_hit(1);
+        scene.add_contact(p(1.0, 0.0));
+        scene.add_normal(p(1.0, 0.0), Vector2D { x: 1.0, y: 0.0 });
+        scene.add_closest_points(p(1.0, 0.0), p(1.0, 0.0));
+        let svg = scene.to_svg();
+        assert!(svg.starts_with("<svg") && svg.ends_with("</svg>"));
+        assert_eq!(svg.matches("<circle").count(), 2 + 1 + 2);
+        assert_eq!(svg.matches("<polygon").count(), 1);
+        let hit_group = svg.find(r#"stroke="red""#).unwrap();
+        assert!(svg[hit_group..].contains(r#"<circle cx="2" cy="0" r="1"/>"#));
+        assert!(svg.find(r#"<circle cx="0" cy="0" r="1"/>"#).unwrap() < hit_group);
+    }
+
+    #[test]
+    fn test_write_file() {
+        let mut scene = DebugScene::new();
+        scene.add_shape(Affine2D::new(0.0, 0.0, Circle { radius: 1.0 }.into()));
+        let path = std::env::temp_dir().join("geometry_debug_scene_test.svg");
+        scene.write(&path).unwrap();
+        assert_eq!(std::fs::read_to_string(&path).unwrap(), scene.to_svg());
+        std::fs::remove_file(&path).unwrap();
+    }
+}
@@ -0,0 +1,211 @@
+use geometry::{
+    delaunay::{DelaunayTriangulation, VoronoiDiagram},
+    point::{Point2D, dist2},
+    shapes::{affine::EmbedInAffine2D, rectangle::Rectangle},
+};
+
+fn assert_close(a: f32, b: f32) {
+    assert!((a - b).abs() < 0.001, "{a} != {b}");
+}
+
+fn grid(n: usize) -> Vec<Point2D> {
+    (0..n * n)
+        .map(|i| Point2D {
+            x: (i % n) as f32 + 0.1 * ((i * 7) % 3) as f32,
+            y: (i / n) as f32 + 0.1 * ((i * 5) % 4) as f32,
+        })
+        .collect()
+}
+
+/// Points in convex position on a shallow arc, nearly collinear
+fn shallow_arc() -> Vec<Point2D> {
+    (0..12)
+        .map(|i| {
+            let x = -55.0 + 10.0 * i as f32;
+            Point2D {
+                x,
+                y: 1.1 * (1.0 - (x / 55.0).powi(2)),
+            }
+        })
+        .collect()
+}
+
+mod delaunay {
+    use super::*;
+
+    #[test]
+    fn test_square_has_two_triangles() {
+        let points = vec![
+            Point2D { x: 0.0, y: 0.0 },
+            Point2D { x: 2.0, y: 0.0 },
+            Point2D { x: 2.0, y: 1.0 },
+            Point2D { x: 0.0, y: 1.2 },
+        ];
+        let triangulation = DelaunayTriangulation::new(&points);
+        assert!(triangulation.triangles.len() == 2);
+        assert!(triangulation.get_edges().len() == 5);
+    }
+
+    #[test]
+    fn test_empty_circumcircles() {
+        let points = grid(6);
+        let triangulation = DelaunayTriangulation::new(&points);
+        let mesh = triangulation.to_mesh();
+        assert!(mesh.triangle_count() > 0);
+        for i in 0..mesh.triangle_count() {
+            let [a, b, c] = mesh.get_triangle(i);
+            assert!((b - a).cross(&(c - a)) > 0.0);
+            let (ab, ac) = (b - a, c - a);
+            let d = 2.0 * ab.cross(&ac);
+            let center = Point2D {
+                x: a.x + (ac.y * ab.dot(&ab) - ab.y * ac.dot(&ac)) / d,
+                y: a.y + (ab.x * ac.dot(&ac) - ac.x * ab.dot(&ab)) / d,
+            };
+            let radius2 = dist2(&center, &a);
+            for p in &points {
+                assert!(dist2(&center, p) > radius2 - 0.001);
+            }
+        }
+    }
+
+    #[test]
+    fn test_duplicates_and_degenerate_input() {
+        let line: Vec<Point2D> = (0..4)
+            .map(|i| Point2D {
+                x: i as f32,
+                y: 0.0,
+            })
+            .collect();
+        assert!(DelaunayTriangulation::new(&line).triangles.is_empty());
+        let mut points = grid(3);
+        points.push(points[4]);
+        let triangulation = DelaunayTriangulation::new(&points);
+        assert!(triangulation.get_neighbours()[9].is_empty());
+        assert!(!triangulation.get_neighbours()[4].is_empty());
+    }
+
+    #[test]
+    fn test_nearly_collinear_points_keep_hull_triangles() {
+        let points = shallow_arc();
+        let triangulation = DelaunayTriangulation::new(&points);
+        assert_eq!(triangulation.triangles.len(), points.len() - 2);
+        let edges = triangulation.get_edges();
+        for i in 0..points.len() - 1 {
+            assert!(edges.contains(&(i, i + 1)));
+        }
+        assert!(edges.contains(&(0, points.len() - 1)));
+    }
+
+    #[test]
+    fn test_points_closer_than_eps_are_duplicates() {
+        let mut points = grid(3);
+        points.push(Point2D {
+            x: points[4].x + 0.00005,
+            y: points[4].y,
+        });
+        let triangulation = DelaunayTriangulation::new(&points);
+        assert!(triangulation.triangles.iter().all(|t| !t.contains(&9)));
+        let bounds = Rectangle {
+            width: 6.0,
+            height: 6.0,
+        }
+        .embed_affine(&Point2D { x: 1.0, y: 1.0 });
+        let diagram = VoronoiDiagram::new(&points, &bounds);
+        assert!(diagram.cells[9].vertices.is_empty());
+        assert!(!diagram.cells[4].vertices.is_empty());
+        assert!(diagram.nearest_site(&points[9]) == Some(4));
+    }
+}
+
+mod voronoi {
+    use super::*;
+
+    #[test]
+    fn test_cells_cover_bounds() {
+        let points = grid(5);
+        let bounds = Rectangle {
+            width: 8.0,
+            height: 8.0,
+        }
+        .embed_affine(&Point2D { x: 2.0, y: 2.0 });
+        let diagram = VoronoiDiagram::new(&points, &bounds);
+        let area: f32 = diagram
+            .cells
+            .iter()
+            .map(|c| c.signed_double_area() / 2.0)
+            .sum();
+        assert_close(area, 64.0);
+        for (cell, site) in diagram.cells.iter().zip(&points) {
+            assert!(cell.winds_around(site));
+        }
+    }
+
+    #[test]
+    fn test_collinear_sites() {
+        let points: Vec<Point2D> = (0..3)
+            .map(|i| Point2D {
+                x: i as f32,
+                y: 0.0,
+            })
+            .collect();
+        let bounds = Rectangle {
+            width: 4.0,
+            height: 2.0,
+        }
+        .embed_affine(&Point2D { x: 1.0, y: 0.0 });
+        let diagram = VoronoiDiagram::new(&points, &bounds);
+        assert_close(diagram.cells[1].signed_double_area() / 2.0, 2.0);
+        assert_close(diagram.cells[0].signed_double_area() / 2.0, 3.0);
+    }
+
+    #[test]
+    fn test_nearest_site() {
+        let points = grid(5);
+        let bounds = Rectangle {
+            width: 8.0,
+            height: 8.0,
+        }
+        .embed_affine(&Point2D { x: 2.0, y: 2.0 });
+        let diagram = VoronoiDiagram::new(&points, &bounds);
+        for query in [
+            Point2D { x: 3.3, y: 0.7 },
+            Point2D { x: -1.0, y: 5.0 },
+            Point2D { x: 2.45, y: 2.55 },
+        ] {
+            let expected = (0..points.len())
+                .min_by(|&i, &j| dist2(&points[i], &query).total_cmp(&dist2(&points[j], &query)));
+            assert!(diagram.nearest_site(&query) == expected);
+        }
+        let empty = VoronoiDiagram::new(&[], &bounds);
+        assert!(empty.nearest_site(&Point2D::zero()).is_none());
+    }
+
+    #[test]
+    fn test_nearly_collinear_sites() {
+        let points = shallow_arc();
+        let bounds = Rectangle {
+            width: 120.0,
+            height: 40.0,
+        }
+        .embed_affine(&Point2D::zero());
+        let diagram = VoronoiDiagram::new(&points, &bounds);
+        let area: f32 = diagram
+            .cells
+            .iter()
+            .map(|c| c.signed_double_area() / 2.0)
+            .sum();
+        assert!((area - 4800.0).abs() < 1.0, "{area}");
+        for (cell, site) in diagram.cells.iter().zip(&points) {
+            assert!(cell.winds_around(site));
+        }
+        for query in [
+            Point2D { x: 3.0, y: 10.0 },
+            Point2D { x: -52.0, y: -8.0 },
+            Point2D { x: 44.0, y: 0.5 },
+        ] {
+            let expected = (0..points.len())
+                .min_by(|&i, &j| dist2(&points[i], &query).total_cmp(&dist2(&points[j], &query)));
+            assert!(diagram.nearest_site(&query) == expected);
+        }
+    }
+}
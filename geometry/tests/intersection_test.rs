@@ -0,0 +1,104 @@
+use geometry::{
+    point::Point2D,
+    shapes::{
+        affine::TypedAffine2D,
+        segment::{Segment2D, SegmentIntersection},
+    },
+    vector::Vector2D,
+};
+
+fn segment(x1: f32, y1: f32, x2: f32, y2: f32) -> TypedAffine2D<Segment2D> {
+    TypedAffine2D::<Segment2D>::from_points(&Point2D { x: x1, y: y1 }, &Point2D { x: x2, y: y2 })
+}
+
+mod construction_from_points {
+    use super::*;
+    use geometry::shapes::line::Line2D;
+
+    #[test]
+    fn test_segment_from_points() {
+        let s = segment(0.0, 0.0, 2.0, 2.0);
+        assert!(s.point == Point2D { x: 1.0, y: 1.0 });
+        assert!(s.get_start() == Point2D { x: 0.0, y: 0.0 });
+        assert!(s.get_end() == Point2D { x: 2.0, y: 2.0 });
+        assert!(s.get_vector() == Vector2D { x: 2.0, y: 2.0 });
+        assert!((s.shape.length - f32::sqrt(8.0)).abs() < 0.0001);
+    }
+
+    #[test]
+    fn test_line_from_points() {
+        let p1 = Point2D { x: 1.0, y: 1.0 };
+        let p2 = Point2D { x: 1.0, y: 3.0 };
+        let l = TypedAffine2D::<Line2D>::from_points(&p1, &p2).unwrap();
+        assert!(l.shape.get_direction() == Vector2D { x: 0.0, y: 1.0 });
+        assert!(TypedAffine2D::<Line2D>::from_points(&p1, &p1).is_none());
+    }
+}
+
+mod segment_segment_intersection {
+    use super::*;
+
+    #[test]
+    fn test_cross() {
+        let s1 = segment(0.0, 0.0, 2.0, 2.0);
+        let s2 = segment(0.0, 2.0, 2.0, 0.0);
+        match s1.segment_intersection(&s2) {
+            Some(SegmentIntersection::Point(p)) => assert!(p == Point2D { x: 1.0, y: 1.0 }),
+            other => panic!("expected point, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn test_touch_at_end_point() {
+        let s1 = segment(0.0, 0.0, 2.0, 0.0);
+        let s2 = segment(2.0, 0.0, 3.0, 5.0);
+        match s1.segment_intersection(&s2) {
+            Some(SegmentIntersection::Point(p)) => assert!(p == Point2D { x: 2.0, y: 0.0 }),
+            other => panic!("expected point, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn test_no_intersection() {
+        let s1 = segment(0.0, 0.0, 2.0, 2.0);
+        let s2 = segment(3.0, 0.0, 2.0, 1.5);
+        assert!(s1.segment_intersection(&s2).is_none());
+    }
+
+    #[test]
+    fn test_parallel() {
+        let s1 = segment(0.0, 0.0, 2.0, 0.0);
+        let s2 = segment(0.0, 1.0, 2.0, 1.0);
+        assert!(s1.segment_intersection(&s2).is_none());
+    }
+
+    #[test]
+    fn test_collinear_overlap() {
+        let s1 = segment(0.0, 0.0, 4.0, 0.0);
+        let s2 = segment(5.0, 0.0, 2.0, 0.0);
+        match s1.segment_intersection(&s2) {
+            Some(SegmentIntersection::Segment(s)) => {
+                assert!(s.get_start() == Point2D { x: 2.0, y: 0.0 });
+                assert!(s.get_end() == Point2D { x: 4.0, y: 0.0 });
+            }
+            other => panic!("expected segment, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn test_collinear_touch() {
+        let s1 = segment(0.0, 0.0, 1.0, 1.0);
+        let s2 = segment(1.0, 1.0, 3.0, 3.0);
+        match s1.segment_intersection(&s2) {
+            Some(SegmentIntersection::Point(p)) => assert!(p == Point2D { x: 1.0, y: 1.0 }),
+            other => panic!("expected point, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn test_collinear_disjoint() {
+        let s1 = segment(0.0, 0.0, 1.0, 0.0);
+        let s2 = segment(2.0, 0.0, 3.0, 0.0);
+        assert!(s1.segment_intersection(&s2).is_none());
+    }
+}
@@ -0,0 +1,463 @@
+use geometry::{
+    collisions::Collisions,
+    layout::{
+        arrange::{Alignment, Axis, align, distribute},
+        force::{ForceLayout, ForceLayoutSettings},
+        layered::{LayeredLayoutSettings, layered_layout},
+        overlap::remove_overlaps,
+        tree::{TreeLayoutSettings, TreeOrientation, tree_layout},
+    },
+    point::{Point2D, dist},
+    shapes::{
+        Shape2D,
+        affine::{Affine2D, EmbedInAffine2D, TypedAffine2D},
+        circle::Circle,
+        rectangle::Rectangle,
+    },
+};
+
+fn node(x: f32, y: f32, width: f32, height: f32) -> TypedAffine2D<Rectangle> {
+    Rectangle { width, height }.embed_affine(&Point2D { x, y })
+}
+
+fn assert_no_overlaps(nodes: &[TypedAffine2D<Rectangle>], gap: f32) {
+    for (i, a) in nodes.iter().enumerate() {
+        for b in &nodes[i + 1..] {
+            let (a, b) = (a.inflated(gap / 2.0 - 0.001), b.inflated(gap / 2.0 - 0.001));
+            assert!(!a.check_collision(&b).unwrap(), "{a:?} overlaps {b:?}");
+        }
+    }
+}
+
+fn assert_order_kept(before: &[TypedAffine2D<Rectangle>], after: &[TypedAffine2D<Rectangle>]) {
+    for i in 0..before.len() {
+        for j in 0..before.len() {
+            if before[i].point.x < before[j].point.x {
+                assert!(after[i].point.x <= after[j].point.x);
+            }
+            if before[i].point.y < before[j].point.y {
+                assert!(after[i].point.y <= after[j].point.y);
+            }
+        }
+    }
+}
+
+mod overlap {
+    use super::*;
+
+    #[test]
+    fn test_separated_nodes_stay() {
+        let nodes = vec![node(0.0, 0.0, 2.0, 2.0), node(5.0, 1.0, 2.0, 2.0)];
+        let moved = remove_overlaps(&nodes, 0.5);
+        for (a, b) in nodes.iter().zip(&moved) {
+            assert!(a.point == b.point);
+        }
+    }
+
+    #[test]
+    fn test_pair_moves_symmetrically() {
+        let nodes = vec![node(0.0, 0.0, 2.0, 4.0), node(1.0, 0.5, 2.0, 4.0)];
+        let moved = remove_overlaps(&nodes, 0.0);
+        assert_no_overlaps(&moved, 0.0);
+        assert!(moved[0].point == Point2D { x: -0.5, y: 0.0 });
+        assert!(moved[1].point == Point2D { x: 1.5, y: 0.5 });
+    }
+
+    #[test]
+    fn test_stacked_at_origin() {
+        let nodes: Vec<_> = (0..12)
+            .map(|i| node(0.0, 0.0, 1.0 + (i % 3) as f32, 1.0 + (i % 2) as f32))
+            .collect();
+        let moved = remove_overlaps(&nodes, 0.2);
+        assert_no_overlaps(&moved, 0.2);
+    }
+
+    #[test]
+    fn test_cluster_keeps_order() {
+        let nodes: Vec<_> = (0..30)
+            .map(|i| {
+                let (x, y) = ((i * 37 % 11) as f32 * 0.7, (i * 53 % 13) as f32 * 0.6);
+                node(x, y, 2.0 + (i % 4) as f32 * 0.5, 1.0 + (i % 3) as f32 * 0.5)
+            })
+            .collect();
+        let moved = remove_overlaps(&nodes, 0.1);
+        assert_no_overlaps(&moved, 0.1);
+        assert_order_kept(&nodes, &moved);
+    }
+}
+
+mod force {
+    use super::*;
+
+    fn points(n: usize) -> Vec<Affine2D> {
+        (0..n)
+            .map(|i| Affine2D::new((i % 5) as f32, (i / 5) as f32, Shape2D::Point))
+            .collect()
+    }
+
+    #[test]
+    fn test_pair_settles_near_edge_length() {
+        let mut layout = ForceLayout::new(points(2), vec![(0, 1)], ForceLayoutSettings::default());
+        layout.settings.gravity = 0.0;
+        layout.run(500);
+        let d = dist(&layout.nodes[0].point, &layout.nodes[1].point);
+        assert!((d - 50.0).abs() < 1.0, "{d}");
+    }
+
+    #[test]
+    fn test_shapes_keep_their_size_apart() {
+        let circle = |x| {
+            Circle { radius: 20.0 }
+                .embed_affine(&Point2D { x, y: 0.0 })
+                .into()
+        };
+        let settings = ForceLayoutSettings {
+            gravity: 0.0,
+            ..Default::default()
+        };
+        let mut layout = ForceLayout::new(vec![circle(0.0), circle(1.0)], vec![(0, 1)], settings);
+        layout.run(500);
+        let d = dist(&layout.nodes[0].point, &layout.nodes[1].point);
+        assert!(d > 60.0, "{d}");
+    }
+
+    #[test]
+    fn test_pinned_node_stays() {
+        let mut layout = ForceLayout::new(
+            points(10),
+            (1..10).map(|i| (0, i)).collect(),
+            ForceLayoutSettings::default(),
+        );
+        layout.pin(0, true);
+        layout.run(100);
+        assert!(layout.nodes[0].point == Point2D::zero());
+        for node in &layout.nodes[1..] {
+            assert!(dist(&node.point, &Point2D::zero()) > 10.0);
+        }
+    }
+
+    #[test]
+    fn test_coincident_nodes_spread() {
+        let nodes = vec![Affine2D::new(0.0, 0.0, Shape2D::Point); 20];
+        let mut layout = ForceLayout::new(nodes, vec![], ForceLayoutSettings::default());
+        layout.run(200);
+        for (i, a) in layout.nodes.iter().enumerate() {
+            for b in &layout.nodes[i + 1..] {
+                assert!(dist(&a.point, &b.point) > 5.0);
+            }
+        }
+    }
+
+    #[test]
+    fn test_coincident_pairs_are_pushed_apart() {
+        // far nodes on a circle around the origin, 0 and 12 share the origin and 1, 13
+        // and 25 share another spot
+        let nodes: Vec<Affine2D> = (0..26)
+            .map(|i| {
+                match i {
+                    0 | 12 => return Affine2D::new(0.0, 0.0, Shape2D::Point),
+                    1 | 13 | 25 => return Affine2D::new(100.0, 0.0, Shape2D::Point),
+                    _ => {}
+                }
+                let a = i as f32 / 26.0 * std::f32::consts::TAU;
+                Affine2D::new(500.0 * a.cos(), 500.0 * a.sin(), Shape2D::Point)
+            })
+            .collect();
+        let mut layout = ForceLayout::new(nodes, vec![], ForceLayoutSettings::default());
+        layout.run(200);
+        for (a, b) in [(0, 12), (1, 13), (1, 25), (13, 25)] {
+            assert!(dist(&layout.nodes[a].point, &layout.nodes[b].point) > 5.0);
+        }
+    }
+
+    #[test]
+    fn test_barnes_hut_matches_exact() {
+        let edges: Vec<_> = (1..40).map(|i| (i / 2, i)).collect();
+        let exact_settings = ForceLayoutSettings {
+            theta: 0.0,
+            ..Default::default()
+        };
+        let mut exact = ForceLayout::new(points(40), edges.clone(), exact_settings);
+        let mut approximate = ForceLayout::new(points(40), edges, ForceLayoutSettings::default());
+        exact.step();
+        approximate.step();
+        for (a, b) in exact.nodes.iter().zip(&approximate.nodes) {
+            assert!(dist(&a.point, &b.point) < 5.0);
+        }
+    }
+
+    #[test]
+    fn test_incremental_steps() {
+        let mut layout = ForceLayout::new(
+            points(6),
+            (1..6).map(|i| (i - 1, i)).collect(),
+            ForceLayoutSettings::default(),
+        );
+        let steps = layout.run(2000);
+        assert!(steps < 2000);
+        assert!(layout.step() < layout.settings.min_temperature + 0.001);
+        let added = layout.add_node(Affine2D::new(0.0, 0.0, Shape2D::Point));
+        layout.add_edge(5, added);
+        layout.reheat();
+        assert!(layout.step() > 1.0);
+    }
+}
+
+mod layered {
+    use super::*;
+
+    fn sizes(n: usize) -> Vec<Rectangle> {
+        (0..n)
+            .map(|i| Rectangle {
+                width: 40.0 + (i % 3) as f32 * 10.0,
+                height: 20.0,
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_layers_follow_edges() {
+        let edges = vec![(0, 1), (0, 2), (1, 3), (2, 3), (3, 4), (0, 4)];
+        let layout = layered_layout(&sizes(5), &edges, &LayeredLayoutSettings::default());
+        assert!(layout.layers == vec![0, 1, 1, 2, 3]);
+        for &(a, b) in &edges {
+            assert!(layout.nodes[a].point.y > layout.nodes[b].point.y);
+        }
+        assert_no_overlaps(&layout.nodes, 29.9);
+        // long edge goes through the two layers in between
+        assert!(layout.routes[5].points.len() == 4);
+        assert!(layout.routes[5].points[0].y == layout.nodes[0].get_bottom());
+        assert!(layout.routes[5].points[3].y == layout.nodes[4].get_top());
+    }
+
+    #[test]
+    fn test_crossings_removed() {
+        // drawn in index order the edges to 3 and 4 cross
+        let edges = vec![(0, 1), (0, 2), (1, 4), (2, 3)];
+        let layout = layered_layout(&sizes(5), &edges, &LayeredLayoutSettings::default());
+        let x = |v: usize| layout.nodes[v].point.x;
+        assert!((x(1) < x(2)) == (x(4) < x(3)));
+    }
+
+    #[test]
+    fn test_cycles_are_broken() {
+        let edges = vec![(0, 1), (1, 2), (2, 0), (1, 1)];
+        let layout = layered_layout(&sizes(3), &edges, &LayeredLayoutSettings::default());
+        assert!(layout.layers == vec![0, 1, 2]);
+        let back = &layout.routes[2].points;
+        assert!(back.first().unwrap().y == layout.nodes[2].get_top());
+        assert!(back.last().unwrap().y == layout.nodes[0].get_bottom());
+        assert!(layout.routes[3].points.is_empty());
+    }
+
+    #[test]
+    fn test_chain_is_straight() {
+        let edges: Vec<_> = (1..6).map(|i| (i - 1, i)).collect();
+        let layout = layered_layout(&sizes(6), &edges, &LayeredLayoutSettings::default());
+        for node in &layout.nodes {
+            assert!(node.point.x == layout.nodes[0].point.x);
+        }
+    }
+}
+
+mod tree {
+    use super::*;
+
+    fn sizes() -> Vec<Rectangle> {
+        [
+            (40.0, 20.0),
+            (60.0, 20.0),
+            (20.0, 30.0),
+            (40.0, 20.0),
+            (80.0, 20.0),
+            (30.0, 20.0),
+        ]
+        .into_iter()
+        .map(|(width, height)| Rectangle { width, height })
+        .collect()
+    }
+
+    //      0
+    //    / | \
+    //   1  2  3
+    //  / \    |
+    // 4   5   (back-link to 0)
+    fn edges() -> Vec<(usize, usize)> {
+        vec![(0, 1), (0, 2), (0, 3), (1, 4), (1, 5), (3, 0), (2, 5)]
+    }
+
+    #[test]
+    fn test_top_down() {
+        let settings = TreeLayoutSettings::default();
+        let nodes = tree_layout(&sizes(), &edges(), 0, &settings);
+        assert!(nodes[0].point == Point2D { x: 0.0, y: -10.0 });
+        // parents are centered over their first and last child
+        assert!((nodes[0].point.x - (nodes[1].point.x + nodes[3].point.x) / 2.0).abs() < 0.001);
+        assert!((nodes[1].point.x - (nodes[4].point.x + nodes[5].point.x) / 2.0).abs() < 0.001);
+        // levels follow the tallest node of the level above
+        assert!((nodes[1].get_top() - (nodes[0].get_bottom() - 40.0)).abs() < 0.001);
+        assert!((nodes[4].get_top() - (nodes[2].get_bottom() - 40.0)).abs() < 0.001);
+        assert!(nodes[1].point.x < nodes[2].point.x && nodes[2].point.x < nodes[3].point.x);
+        assert_no_overlaps(&nodes, 19.9);
+        // neighbouring subtrees are pushed together
+        assert!((nodes[2].get_left() - nodes[1].get_right() - 20.0).abs() < 0.001);
+    }
+
+    #[test]
+    fn test_left_right() {
+        let settings = TreeLayoutSettings {
+            orientation: TreeOrientation::LeftRight,
+            ..Default::default()
+        };
+        let nodes = tree_layout(&sizes(), &edges(), 0, &settings);
+        assert!(nodes[1].get_left() > nodes[0].get_right());
+        assert!(nodes[4].get_left() > nodes[1].get_right());
+        assert!(nodes[1].point.y > nodes[2].point.y && nodes[2].point.y > nodes[3].point.y);
+        assert_no_overlaps(&nodes, 19.9);
+    }
+
+    #[test]
+    fn test_unreachable_nodes() {
+        let nodes = tree_layout(
+            &sizes(),
+            &[(0, 1), (2, 3)],
+            0,
+            &TreeLayoutSettings::default(),
+        );
+        assert!(nodes[0].point.x == 0.0);
+        assert!(nodes[2].get_top() == nodes[0].get_top());
+        assert_no_overlaps(&nodes, 19.9);
+    }
+
+    #[test]
+    fn test_small_subtrees_are_spread_evenly() {
+        //        0
+        //   / /     \ \
+        //  1  2      3  4
+        // / \          / \
+        // 5 6          7 8
+        let sizes: Vec<Rectangle> = (0..9)
+            .map(|v| Rectangle {
+                width: if v < 5 { 20.0 } else { 100.0 },
+                height: 20.0,
+            })
+            .collect();
+        let edges = [
+            (0, 1),
+            (0, 2),
+            (0, 3),
+            (0, 4),
+            (1, 5),
+            (1, 6),
+            (4, 7),
+            (4, 8),
+        ];
+        let nodes = tree_layout(&sizes, &edges, 0, &TreeLayoutSettings::default());
+        // the wide grandchildren push 4 away from 1, 2 and 3 share the space between
+        assert!((nodes[6].get_right() + 20.0 - nodes[7].get_left()).abs() < 0.001);
+        for (a, b) in [(1, 2), (2, 3), (3, 4)] {
+            assert!((nodes[b].point.x - nodes[a].point.x - 80.0).abs() < 0.001);
+        }
+        assert_no_overlaps(&nodes, 19.9);
+    }
+
+    #[test]
+    fn test_deep_chain() {
+        let n = 100_000;
+        let sizes = vec![
+            Rectangle {
+                width: 10.0,
+                height: 10.0,
+            };
+            n
+        ];
+        let edges: Vec<(usize, usize)> = (1..n).map(|v| (v - 1, v)).collect();
+        let nodes = tree_layout(&sizes, &edges, 0, &TreeLayoutSettings::default());
+        assert!(nodes.iter().all(|node| node.point.x == 0.0));
+        assert!(nodes[n - 1].point.y < nodes[n - 2].point.y);
+    }
+}
+
+mod arrange {
+    use super::*;
+    #[test]
+    fn test_align_sides_and_centers() {
+        let nodes = [
+            node(0.0, 0.0, 10.0, 10.0),
+            node(30.0, 20.0, 20.0, 6.0),
+            node(12.0, -15.0, 4.0, 4.0),
+        ];
+        let left = align(&nodes, Alignment::Left);
+        assert!(left.iter().all(|n| n.get_left() == -5.0));
+        assert!(left.iter().zip(&nodes).all(|(a, b)| a.point.y == b.point.y));
+        let right = align(&nodes, Alignment::Right);
+        assert!(right.iter().all(|n| n.get_right() == 40.0));
+        let top = align(&nodes, Alignment::Top);
+        assert!(top.iter().all(|n| n.get_top() == 23.0));
+        let bottom = align(&nodes, Alignment::Bottom);
+        assert!(bottom.iter().all(|n| n.get_bottom() == -17.0));
+        let centered = align(&nodes, Alignment::HorizontalCenter);
+        assert!(centered.iter().all(|n| n.point.x == 17.5));
+        let middle = align(&nodes, Alignment::VerticalCenter);
+        assert!(middle.iter().all(|n| n.point.y == 3.0));
+    }
+
+    #[test]
+    fn test_align_circles_by_bounding_box() {
+        let circles = [
+            Circle { radius: 1.0 }.embed_affine(&Point2D { x: 0.0, y: 0.0 }),
+            Circle { radius: 3.0 }.embed_affine(&Point2D { x: 5.0, y: 2.0 }),
+        ];
+        let aligned = align(&circles, Alignment::Left);
+        assert!(aligned[0].point == Point2D { x: 0.0, y: 0.0 });
+        assert!(aligned[1].point == Point2D { x: 2.0, y: 2.0 });
+    }
+
+    #[test]
+    fn test_distribute_equal_gaps() {
+        let nodes = [
+            node(0.0, 0.0, 10.0, 10.0),
+            node(100.0, 5.0, 20.0, 10.0),
+            node(20.0, 2.0, 10.0, 10.0),
+            node(35.0, -3.0, 30.0, 10.0),
+        ];
+        let distributed = distribute(&nodes, Axis::Horizontal);
+        let mut sorted = distributed.clone();
+        sorted.sort_by(|a, b| a.get_left().total_cmp(&b.get_left()));
+        assert!(sorted[0].get_left() == -5.0);
+        assert!(sorted[3].get_right() == 110.0);
+        let gaps: Vec<f32> = sorted
+            .windows(2)
+            .map(|w| w[1].get_left() - w[0].get_right())
+            .collect();
+        assert!(gaps.iter().all(|g| (g - gaps[0]).abs() < 0.001));
+        assert!(distributed[2].point.x < distributed[3].point.x);
+        assert!(
+            distributed
+                .iter()
+                .zip(&nodes)
+                .all(|(a, b)| a.point.y == b.point.y)
+        );
+    }
+
+    #[test]
+    fn test_distribute_vertically() {
+        let nodes = [
+            node(0.0, 0.0, 10.0, 10.0),
+            node(3.0, 12.0, 10.0, 2.0),
+            node(-2.0, 50.0, 10.0, 20.0),
+        ];
+        let distributed = distribute(&nodes, Axis::Vertical);
+        assert!(distributed[0].point.y == 0.0);
+        assert!(distributed[2].point.y == 50.0);
+        let below = distributed[1].get_bottom() - distributed[0].get_top();
+        let above = distributed[2].get_bottom() - distributed[1].get_top();
+        assert!((below - above).abs() < 0.001);
+        assert!(
+            distributed
+                .iter()
+                .zip(&nodes)
+                .all(|(a, b)| a.point.x == b.point.x)
+        );
+    }
+}
@@ -0,0 +1,160 @@
+use geometry::{
+    measure::Measure,
+    point::Point2D,
+    shapes::{
+        affine::{Affine2D, EmbedInAffine2D},
+        circle::Circle,
+        polygon::Polygon,
+        rectangle::Rectangle,
+        rounded_rectangle::RoundedRectangle,
+        segment::Segment2D,
+    },
+};
+
+fn assert_close(a: f32, b: f32) {
+    assert!((a - b).abs() < 0.001, "{a} != {b}");
+}
+
+fn triangle() -> Polygon {
+    Polygon::new(vec![
+        Point2D { x: 0.0, y: 0.0 },
+        Point2D { x: 3.0, y: 0.0 },
+        Point2D { x: 0.0, y: 3.0 },
+    ])
+}
+
+mod rectangle_measure {
+    use super::*;
+    #[test]
+    fn test_rectangle_measures() {
+        let r = Rectangle {
+            width: 2.0,
+            height: 4.0,
+        }
+        .embed_affine(&Point2D { x: 1.0, y: 1.0 });
+        assert_close(r.area(), 8.0);
+        assert_close(r.perimeter(), 12.0);
+        assert!(r.centroid() == Point2D { x: 1.0, y: 1.0 });
+        let moment = r.second_moment();
+        assert_close(moment.ixx, 2.0 * 64.0 / 12.0);
+        assert_close(moment.iyy, 4.0 * 8.0 / 12.0);
+        assert_close(moment.ixy, 0.0);
+    }
+}
+
+mod rounded_rectangle_measure {
+    use super::*;
+    use std::f32::consts::PI;
+    #[test]
+    fn test_rounded_rectangle_measures() {
+        let rr = RoundedRectangle {
+            width: 6.0,
+            height: 4.0,
+            radius: 1.0,
+        }
+        .embed_affine(&Point2D { x: 1.0, y: 1.0 });
+        assert_close(rr.area(), 24.0 - 4.0 + PI);
+        assert_close(rr.perimeter(), 20.0 - 8.0 + 2.0 * PI);
+        assert!(rr.centroid() == Point2D { x: 1.0, y: 1.0 });
+        assert_close(rr.second_moment().ixy, 0.0);
+    }
+
+    #[test]
+    fn test_degenerate_rounded_rectangles() {
+        // no radius is a rectangle, full radius on a square is a circle
+        let square = RoundedRectangle {
+            width: 2.0,
+            height: 4.0,
+            radius: 0.0,
+        }
+        .embed_affine(&Point2D::zero());
+        let moment = square.second_moment();
+        assert_close(moment.ixx, 2.0 * 64.0 / 12.0);
+        assert_close(moment.iyy, 4.0 * 8.0 / 12.0);
+        let circle = RoundedRectangle {
+            width: 4.0,
+            height: 4.0,
+            radius: 5.0,
+        }
+        .embed_affine(&Point2D::zero());
+        assert_close(circle.area(), 4.0 * PI);
+        assert_close(circle.perimeter(), 4.0 * PI);
+        assert_close(circle.second_moment().ixx, PI * 16.0 / 4.0);
+    }
+}
+
+mod circle_measure {
+    use super::*;
+    use std::f32::consts::PI;
+    #[test]
+    fn test_circle_measures() {
+        let c = Circle { radius: 2.0 }.embed_affine(&Point2D::zero());
+        assert_close(c.area(), 4.0 * PI);
+        assert_close(c.perimeter(), 4.0 * PI);
+        assert_close(c.second_moment().polar(), PI * 8.0);
+    }
+}
+
+mod segment_measure {
+    use super::*;
+    use geometry::angle::Angle;
+    #[test]
+    fn test_segment_measures() {
+        let s = Segment2D {
+            angle: Angle::degrees(30.0),
+            length: 5.0,
+        }
+        .embed_affine(&Point2D { x: 1.0, y: 2.0 });
+        assert_close(s.area(), 0.0);
+        assert_close(s.perimeter(), 5.0);
+        assert!(s.centroid() == Point2D { x: 1.0, y: 2.0 });
+    }
+}
+
+mod polygon_measure {
+    use super::*;
+    #[test]
+    fn test_triangle_measures() {
+        let p = triangle().embed_affine(&Point2D { x: 1.0, y: 1.0 });
+        assert_close(p.area(), 4.5);
+        assert_close(p.perimeter(), 6.0 + f32::sqrt(18.0));
+        assert!(p.centroid() == Point2D { x: 2.0, y: 2.0 });
+        let moment = p.second_moment();
+        // right triangle with legs b, h: Ixx = b h^3 / 36, Ixy = -b^2 h^2 / 72
+        assert_close(moment.ixx, 81.0 / 36.0);
+        assert_close(moment.iyy, 81.0 / 36.0);
+        assert_close(moment.ixy, -81.0 / 72.0);
+    }
+
+    #[test]
+    fn test_clockwise_polygon_matches_rectangle() {
+        let square = Polygon::new(vec![
+            Point2D { x: -1.0, y: -2.0 },
+            Point2D { x: -1.0, y: 2.0 },
+            Point2D { x: 1.0, y: 2.0 },
+            Point2D { x: 1.0, y: -2.0 },
+        ])
+        .embed_affine(&Point2D { x: 5.0, y: 5.0 });
+        let rect = Rectangle {
+            width: 2.0,
+            height: 4.0,
+        }
+        .embed_affine(&Point2D { x: 5.0, y: 5.0 });
+        assert_close(square.area(), rect.area());
+        assert!(square.centroid() == rect.centroid());
+        assert_close(square.second_moment().ixx, rect.second_moment().ixx);
+        assert_close(square.second_moment().iyy, rect.second_moment().iyy);
+    }
+}
+
+mod affine_measure {
+    use super::*;
+    #[test]
+    fn test_affine_dispatch() {
+        let aff = Affine2D::new(1.0, 1.0, triangle().into());
+        assert_close(aff.area(), 4.5);
+        assert!(aff.centroid() == Point2D { x: 2.0, y: 2.0 });
+        let point = Affine2D::new(1.0, 1.0, geometry::shapes::Shape2D::Point);
+        assert_close(point.area(), 0.0);
+    }
+}
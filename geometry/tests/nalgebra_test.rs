@@ -0,0 +1,114 @@
+#![cfg(feature = "nalgebra")]
+
+use geometry::{
+    angle::Angle,
+    bounding::OrientedRectangle,
+    point::Point2D,
+    shapes::{
+        Shape2D,
+        affine::{Affine2D, TypedAffine2D},
+        circle::Circle,
+        rectangle::Rectangle,
+    },
+    vector::Vector2D,
+    viewport::Viewport,
+};
+use nalgebra::{Isometry2, Matrix3, Point2, Translation2, UnitComplex, Vector2};
+
+fn assert_close(a: f32, b: f32) {
+    assert!((a - b).abs() < 0.001, "{a} != {b}");
+}
+
+mod conversions {
+    use super::*;
+    #[test]
+    fn test_points_and_vectors() {
+        let p: Point2<f32> = Point2D { x: 1.0, y: 2.0 }.into();
+        assert!(p == Point2::new(1.0, 2.0));
+        assert!(Point2D::from(p) == Point2D { x: 1.0, y: 2.0 });
+        let v: Vector2<f32> = Vector2D { x: 3.0, y: -4.0 }.into();
+        assert!(v == Vector2::new(3.0, -4.0));
+        let back = Vector2D::from(v);
+        assert!(back.x == 3.0 && back.y == -4.0);
+        let t: Translation2<f32> = Vector2D { x: 1.0, y: 1.0 }.into();
+        assert!(t.transform_point(&p) == Point2::new(2.0, 3.0));
+    }
+
+    #[test]
+    fn test_angles() {
+        let rotation: UnitComplex<f32> = Angle::degrees(90.0).into();
+        let turned = rotation * Vector2::new(1.0, 0.0);
+        assert_close(turned.x, 0.0);
+        assert_close(turned.y, 1.0);
+        assert!(Angle::from(rotation) == Angle::degrees(90.0));
+    }
+}
+
+mod transforms {
+    use super::*;
+    #[test]
+    fn test_oriented_rectangle_isometry_maps_corners() {
+        let rect = OrientedRectangle {
+            center: Point2D { x: 1.0, y: 1.0 },
+            rect: Rectangle {
+                width: 4.0,
+                height: 2.0,
+            },
+            angle: Angle::degrees(30.0),
+        };
+        let isometry: Isometry2<f32> = (&rect).into();
+        let corner: Point2D = isometry.transform_point(&Point2::new(-2.0, -1.0)).into();
+        assert!(corner == rect.get_vertices()[0]);
+    }
+
+    #[test]
+    fn test_isometry_to_oriented_rectangle() {
+        let isometry = Isometry2::new(Vector2::new(1.0, 1.0), Angle::degrees(30.0).as_radians());
+        let rect = OrientedRectangle::from((
+            isometry,
+            Rectangle {
+                width: 4.0,
+                height: 2.0,
+            },
+        ));
+        assert!(rect.center == Point2D { x: 1.0, y: 1.0 });
+        assert!(rect.angle == Angle::degrees(30.0));
+        let back: Isometry2<f32> = (&rect).into();
+        assert!(back.translation == isometry.translation);
+        assert_close(back.rotation.angle(), isometry.rotation.angle());
+    }
+
+    #[test]
+    fn test_affine_translations() {
+        let circle = TypedAffine2D::new(Point2D { x: 2.0, y: -3.0 }, Circle { radius: 1.0 });
+        let translation: Translation2<f32> = (&circle).into();
+        assert!(translation == Translation2::new(2.0, -3.0));
+        let isometry: Isometry2<f32> = (&circle).into();
+        assert_close(isometry.rotation.angle(), 0.0);
+        let center: Point2D = isometry.transform_point(&Point2::origin()).into();
+        assert!(center == circle.point);
+        let back = TypedAffine2D::from((translation, Circle { radius: 1.0 }));
+        assert!(back.point == circle.point);
+
+        let shape: Affine2D = circle.into();
+        let translation: Translation2<f32> = (&shape).into();
+        assert!(translation == Translation2::new(2.0, -3.0));
+        let isometry: Isometry2<f32> = (&shape).into();
+        assert!(isometry.translation == translation);
+        let back = Affine2D::from((translation, Shape2D::Point));
+        assert!(back.point == shape.point);
+    }
+
+    #[test]
+    fn test_viewport_matrix() {
+        let mut viewport = Viewport::new(200.0, 100.0);
+        viewport.zoom_at(2.0, &Point2D { x: 50.0, y: 50.0 });
+        viewport.rotate_at(&Angle::degrees(20.0), &Point2D { x: 10.0, y: 20.0 });
+        let matrix: Matrix3<f32> = (&viewport).into();
+        let world = Point2D { x: 3.0, y: -7.0 };
+        let screen = matrix.transform_point(&world.into());
+        let expected = viewport.world_to_screen(&world);
+        assert_close(screen.x, expected.x);
+        assert_close(screen.y, expected.y);
+    }
+}
@@ -0,0 +1,156 @@
+use std::f32::consts::PI;
+
+use geometry::{
+    clipping::difference,
+    offset::{EndCap, JoinStyle, offset_polygon, offset_polyline},
+    point::Point2D,
+    shapes::{
+        polygon::{Polygon, PolygonWithHoles},
+        polyline::Polyline,
+    },
+};
+
+fn assert_close(a: f32, b: f32, tolerance: f32) {
+    assert!((a - b).abs() < tolerance, "{a} != {b}");
+}
+
+fn square(x: f32, y: f32, size: f32) -> PolygonWithHoles {
+    PolygonWithHoles::new(
+        Polygon::new(vec![
+            Point2D { x, y },
+            Point2D { x: x + size, y },
+            Point2D {
+                x: x + size,
+                y: y + size,
+            },
+            Point2D { x, y: y + size },
+        ]),
+        vec![],
+    )
+}
+
+fn total_area(region: &[PolygonWithHoles]) -> f32 {
+    region.iter().map(|p| p.area()).sum()
+}
+
+mod polygon_inflate {
+    use super::*;
+    #[test]
+    fn test_miter() {
+        let result = offset_polygon(&square(0.0, 0.0, 2.0), 1.0, JoinStyle::Miter { limit: 2.0 });
+        assert_eq!(result.len(), 1);
+        assert_eq!(result[0].outer.vertices.len(), 4);
+        assert_close(total_area(&result), 16.0, 0.001);
+    }
+
+    #[test]
+    fn test_miter_limit_falls_back_to_bevel() {
+        let result = offset_polygon(&square(0.0, 0.0, 2.0), 1.0, JoinStyle::Miter { limit: 1.2 });
+        assert_eq!(result[0].outer.vertices.len(), 8);
+        assert_close(total_area(&result), 4.0 + 8.0 + 2.0, 0.001);
+    }
+
+    #[test]
+    fn test_bevel() {
+        let result = offset_polygon(&square(0.0, 0.0, 2.0), 1.0, JoinStyle::Bevel);
+        assert_close(total_area(&result), 4.0 + 8.0 + 2.0, 0.001);
+    }
+
+    #[test]
+    fn test_round() {
+        let result = offset_polygon(&square(0.0, 0.0, 2.0), 1.0, JoinStyle::Round);
+        assert_eq!(result.len(), 1);
+        assert_close(total_area(&result), 4.0 + 8.0 + PI, 0.02);
+    }
+
+    #[test]
+    fn test_concave_polygon_fills_notch() {
+        // U shape with a notch of width 1, inflating by 0.6 closes the notch
+        let u = PolygonWithHoles::new(
+            Polygon::new(vec![
+                Point2D { x: 0.0, y: 0.0 },
+                Point2D { x: 3.0, y: 0.0 },
+                Point2D { x: 3.0, y: 3.0 },
+                Point2D { x: 2.0, y: 3.0 },
+                Point2D { x: 2.0, y: 1.0 },
+                Point2D { x: 1.0, y: 1.0 },
+                Point2D { x: 1.0, y: 3.0 },
+                Point2D { x: 0.0, y: 3.0 },
+            ]),
+            vec![],
+        );
+        let result = offset_polygon(&u, 0.6, JoinStyle::Miter { limit: 2.0 });
+        assert_eq!(result.len(), 1);
+        assert!(result[0].holes.is_empty());
+        assert_close(total_area(&result), 4.2 * 4.2, 0.001);
+    }
+}
+
+mod polygon_deflate {
+    use super::*;
+    #[test]
+    fn test_square_shrinks() {
+        let result = offset_polygon(&square(0.0, 0.0, 2.0), -0.5, JoinStyle::Round);
+        assert_eq!(result.len(), 1);
+        assert_close(total_area(&result), 1.0, 0.001);
+    }
+
+    #[test]
+    fn test_square_vanishes() {
+        let result = offset_polygon(
+            &square(0.0, 0.0, 2.0),
+            -1.5,
+            JoinStyle::Miter { limit: 2.0 },
+        );
+        assert!(result.is_empty());
+    }
+
+    #[test]
+    fn test_hole_shrinks_when_inflating() {
+        let frame = difference(&[square(0.0, 0.0, 6.0)], &[square(2.0, 2.0, 2.0)]);
+        let result = offset_polygon(&frame[0], 0.5, JoinStyle::Miter { limit: 2.0 });
+        assert_eq!(result.len(), 1);
+        assert_eq!(result[0].holes.len(), 1);
+        assert_close(total_area(&result), 49.0 - 1.0, 0.001);
+    }
+}
+
+mod polyline_offset {
+    use super::*;
+    fn straight() -> Polyline {
+        Polyline::new(vec![Point2D { x: 0.0, y: 0.0 }, Point2D { x: 4.0, y: 0.0 }])
+    }
+
+    #[test]
+    fn test_butt_caps() {
+        let result = offset_polyline(&straight(), 1.0, JoinStyle::Bevel, EndCap::Butt);
+        assert_eq!(result.len(), 1);
+        assert_close(total_area(&result), 8.0, 0.001);
+    }
+
+    #[test]
+    fn test_square_caps() {
+        let result = offset_polyline(&straight(), 1.0, JoinStyle::Bevel, EndCap::Square);
+        assert_close(total_area(&result), 12.0, 0.001);
+    }
+
+    #[test]
+    fn test_round_caps() {
+        let result = offset_polyline(&straight(), 1.0, JoinStyle::Round, EndCap::Round);
+        assert_close(total_area(&result), 8.0 + PI, 0.02);
+    }
+
+    #[test]
+    fn test_bent_polyline() {
+        let bent = Polyline::new(vec![
+            Point2D { x: 0.0, y: 0.0 },
+            Point2D { x: 4.0, y: 0.0 },
+            Point2D { x: 4.0, y: 4.0 },
+        ]);
+        let result = offset_polyline(&bent, 1.0, JoinStyle::Miter { limit: 2.0 }, EndCap::Butt);
+        assert_eq!(result.len(), 1);
+        assert!(result[0].holes.is_empty());
+        // two 4x2 arms sharing a 2x2 corner square minus what is cut off at the inner corner
+        assert_close(total_area(&result), 5.0 * 2.0 + 4.0 * 2.0 - 2.0, 0.001);
+    }
+}
@@ -0,0 +1,200 @@
+use geometry::{
+    collisions::Collisions,
+    point::Point2D,
+    routing::{
+        Port, PortSide,
+        orthogonal::route_orthogonal,
+        spline::{route_spline, shortest_visible_path},
+    },
+    shapes::{
+        affine::{Affine2D, EmbedInAffine2D, TypedAffine2D},
+        bezier::CubicBezier,
+        circle::Circle,
+        polyline::Polyline,
+        rectangle::Rectangle,
+    },
+};
+
+fn node(x: f32, y: f32, width: f32, height: f32) -> TypedAffine2D<Rectangle> {
+    Rectangle { width, height }.embed_affine(&Point2D { x, y })
+}
+
+fn grown(rect: &TypedAffine2D<Rectangle>, distance: f32) -> TypedAffine2D<Rectangle> {
+    node(
+        rect.point.x,
+        rect.point.y,
+        rect.shape.width + 2.0 * distance,
+        rect.shape.height + 2.0 * distance,
+    )
+}
+
+fn bends(route: &Polyline) -> usize {
+    route.points.len() - 2
+}
+
+fn assert_orthogonal(route: &Polyline) {
+    for w in route.points.windows(2) {
+        assert!(w[0].x == w[1].x || w[0].y == w[1].y, "{:?}", route.points);
+    }
+}
+
+mod orthogonal {
+    use super::*;
+
+    #[test]
+    fn test_straight_route() {
+        let a = node(0.0, 0.0, 2.0, 2.0);
+        let b = node(10.0, 0.0, 2.0, 2.0);
+        let route = route_orthogonal(
+            &[a, b],
+            &Port::on_side(Point2D { x: 1.0, y: 0.0 }, PortSide::Right),
+            &Port::on_side(Point2D { x: 9.0, y: 0.0 }, PortSide::Left),
+            0.5,
+        )
+        .unwrap();
+        assert!(route.points.len() == 2);
+        assert!((route.length() - 8.0).abs() < 0.001);
+    }
+
+    #[test]
+    fn test_route_around_obstacle() {
+        let a = node(0.0, 0.0, 2.0, 2.0);
+        let b = node(10.0, 0.0, 2.0, 2.0);
+        let wall = node(5.0, 0.0, 2.0, 6.0);
+        let obstacles = [a, b, wall];
+        let route = route_orthogonal(
+            &obstacles,
+            &Port::on_side(Point2D { x: 1.0, y: 0.0 }, PortSide::Right),
+            &Port::on_side(Point2D { x: 9.0, y: 0.0 }, PortSide::Left),
+            0.5,
+        )
+        .unwrap();
+        assert_orthogonal(&route);
+        assert!(bends(&route) == 4);
+        for segment in route.get_segments().iter().skip(1).rev().skip(1) {
+            for obstacle in &obstacles {
+                assert!(!grown(obstacle, 0.4).check_collision(segment).unwrap());
+            }
+        }
+    }
+
+    #[test]
+    fn test_minimal_bends_between_sides() {
+        let a = node(0.0, 0.0, 2.0, 2.0);
+        let b = node(10.0, 10.0, 2.0, 2.0);
+        let route = route_orthogonal(
+            &[a, b],
+            &Port::on_side(Point2D { x: 1.0, y: 0.0 }, PortSide::Right),
+            &Port::on_side(Point2D { x: 10.0, y: 9.0 }, PortSide::Bottom),
+            0.5,
+        )
+        .unwrap();
+        assert_orthogonal(&route);
+        assert!(bends(&route) == 1);
+        assert!((route.length() - 18.0).abs() < 0.001);
+    }
+
+    #[test]
+    fn test_free_ports_inside_nodes() {
+        let a = node(0.0, 0.0, 2.0, 2.0);
+        let b = node(6.0, 4.0, 2.0, 2.0);
+        let route = route_orthogonal(
+            &[a.clone(), b.clone()],
+            &Port::new(a.point),
+            &Port::new(b.point),
+            0.5,
+        )
+        .unwrap();
+        assert_orthogonal(&route);
+        assert!(bends(&route) == 1);
+    }
+
+    #[test]
+    fn test_unreachable_target() {
+        let target = node(0.0, 0.0, 2.0, 2.0);
+        let walls = [
+            target.clone(),
+            node(0.0, 3.0, 8.0, 1.0),
+            node(0.0, -3.0, 8.0, 1.0),
+            node(3.0, 0.0, 1.0, 8.0),
+            node(-3.0, 0.0, 1.0, 8.0),
+        ];
+        let route = route_orthogonal(
+            &walls,
+            &Port::on_side(Point2D { x: 10.0, y: 0.0 }, PortSide::Left),
+            &Port::on_side(Point2D { x: 1.0, y: 0.0 }, PortSide::Right),
+            0.5,
+        );
+        assert!(route.is_none());
+    }
+}
+
+mod spline {
+    use super::*;
+
+    fn obstacles() -> Vec<Affine2D> {
+        vec![
+            node(5.0, 0.0, 2.0, 6.0).into(),
+            Circle { radius: 1.5 }
+                .embed_affine(&Point2D { x: 10.0, y: 1.0 })
+                .into(),
+        ]
+    }
+
+    #[test]
+    fn test_bezier_points() {
+        let curve = CubicBezier::from_quadratic(
+            Point2D { x: 0.0, y: 0.0 },
+            Point2D { x: 1.0, y: 2.0 },
+            Point2D { x: 2.0, y: 0.0 },
+        );
+        assert!(curve.point_at(0.0) == curve.start);
+        assert!(curve.point_at(1.0) == curve.end);
+        assert!(curve.point_at(0.5) == Point2D { x: 1.0, y: 1.0 });
+        let line = CubicBezier::line(curve.start, curve.end);
+        assert!((line.flatten(8).length() - 2.0).abs() < 0.001);
+    }
+
+    #[test]
+    fn test_straight_when_unobstructed() {
+        let source = Point2D { x: 0.0, y: 10.0 };
+        let target = Point2D { x: 12.0, y: 10.0 };
+        let curves = route_spline(&obstacles(), &source, &target, 0.5).unwrap();
+        assert!(curves.len() == 1);
+        assert!(curves[0].start == source && curves[0].end == target);
+    }
+
+    #[test]
+    fn test_route_avoids_obstacles() {
+        let source = Point2D { x: 0.0, y: 0.0 };
+        let target = Point2D { x: 14.0, y: 1.0 };
+        let path = shortest_visible_path(&obstacles(), &source, &target, 0.5).unwrap();
+        assert!(path.points.len() > 2);
+        assert!(path.length() > 14.0);
+        let curves = route_spline(&obstacles(), &source, &target, 0.5).unwrap();
+        assert!(curves.first().unwrap().start == source);
+        assert!(curves.last().unwrap().end == target);
+        for pair in curves.windows(2) {
+            assert!(pair[0].end == pair[1].start);
+        }
+        for curve in &curves {
+            for segment in curve.flatten(16).get_segments() {
+                for obstacle in obstacles() {
+                    assert!(!segment.check_collision(&obstacle).unwrap());
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_enclosed_target() {
+        let walls: Vec<Affine2D> = vec![
+            node(0.0, 3.0, 8.0, 1.0).into(),
+            node(0.0, -3.0, 8.0, 1.0).into(),
+            node(3.0, 0.0, 1.0, 8.0).into(),
+            node(-3.0, 0.0, 1.0, 8.0).into(),
+        ];
+        let route = route_spline(&walls, &Point2D { x: 10.0, y: 0.0 }, &Point2D::zero(), 0.5);
+        assert!(route.is_none());
+    }
+}
@@ -0,0 +1,105 @@
+#![cfg(feature = "serde")]
+
+use geometry::{
+    angle::{Angle, AngleRepresentation},
+    point::Point2D,
+    shapes::{
+        Shape2D,
+        affine::{Affine2D, EmbedInAffine2D, TypedAffine2D},
+        polygon::Polygon,
+        rounded_rectangle::RoundedRectangle,
+        segment::Segment2D,
+    },
+    vector::Vector2D,
+};
+use serde::{Serialize, de::DeserializeOwned};
+
+fn round_trip<T: Serialize + DeserializeOwned>(value: &T) -> T {
+    let json = serde_json::to_string(value).unwrap();
+    serde_json::from_str(&json).unwrap()
+}
+
+mod primitives {
+    use super::*;
+    #[test]
+    fn test_point_and_vector() {
+        let p = round_trip(&Point2D { x: 1.5, y: -2.0 });
+        assert!(p == Point2D { x: 1.5, y: -2.0 });
+        let v = round_trip(&Vector2D { x: 3.0, y: 4.0 });
+        assert!(v.x == 3.0 && v.y == 4.0);
+    }
+
+    #[test]
+    fn test_angle_keeps_representation() {
+        let degrees = round_trip(&Angle::degrees(90.0));
+        assert!(matches!(
+            degrees.representation(),
+            AngleRepresentation::Degrees
+        ));
+        assert!(degrees.as_degrees() == 90.0);
+        let radians = round_trip(&Angle::radians(1.0));
+        assert!(matches!(
+            radians.representation(),
+            AngleRepresentation::Radians
+        ));
+        assert!(radians.as_radians() == 1.0);
+    }
+}
+
+mod shapes {
+    use super::*;
+    #[test]
+    fn test_affine_shapes() {
+        let shapes = [
+            Affine2D::new(
+                1.0,
+                2.0,
+                RoundedRectangle {
+                    width: 4.0,
+                    height: 2.0,
+                    radius: 0.5,
+                }
+                .into(),
+            ),
+            Affine2D::new(
+                0.0,
+                0.0,
+                Polygon::new(vec![
+                    Point2D { x: 0.0, y: 0.0 },
+                    Point2D { x: 1.0, y: 0.0 },
+                    Point2D { x: 0.0, y: 1.0 },
+                ])
+                .into(),
+            ),
+            Affine2D::new(3.0, 3.0, Shape2D::Point),
+        ];
+        let restored: Vec<Affine2D> = round_trip(&shapes.to_vec());
+        assert_eq!(restored.len(), 3);
+        assert!(restored[0].point == Point2D { x: 1.0, y: 2.0 });
+        assert!(matches!(
+            &restored[0].shape,
+            Shape2D::RoundedRectangle { rounded_rect } if rounded_rect.radius == 0.5
+        ));
+        assert!(matches!(
+            &restored[1].shape,
+            Shape2D::Polygon { polygon } if polygon.vertices.len() == 3
+        ));
+        assert!(matches!(restored[2].shape, Shape2D::Point));
+    }
+
+    #[test]
+    fn test_typed_affine_segment() {
+        let segment = Segment2D {
+            angle: Angle::degrees(45.0),
+            length: 2.0,
+        }
+        .embed_affine(&Point2D { x: 1.0, y: 1.0 });
+        let restored: TypedAffine2D<Segment2D> = round_trip(&segment);
+        assert!(restored.point == segment.point);
+        assert!(restored.shape.length == 2.0);
+        assert!(matches!(
+            restored.shape.angle.representation(),
+            AngleRepresentation::Degrees
+        ));
+    }
+}
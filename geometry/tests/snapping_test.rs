@@ -0,0 +1,168 @@
+use geometry::{
+    angle::Angle,
+    point::Point2D,
+    shapes::{affine::Affine2D, line::Line2D, rectangle::Rectangle},
+    snapping::{GuideKind, SnapSettings, snap},
+};
+
+fn square(x: f32, y: f32, size: f32) -> Affine2D {
+    Affine2D::new(
+        x,
+        y,
+        Rectangle {
+            width: size,
+            height: size,
+        }
+        .into(),
+    )
+}
+
+fn p(x: f32, y: f32) -> Point2D {
+    Point2D { x, y }
+}
+
+fn no_grid() -> SnapSettings {
+    SnapSettings {
+        grid_size: None,
+        ..SnapSettings::default()
+    }
+}
+
+mod grid {
+    use super::*;
+    #[test]
+    fn test_snap_to_nearest_grid_lines() {
+        let settings = SnapSettings {
+            grid_size: Some(10.0),
+            align: false,
+            equal_spacing: false,
+            ..SnapSettings::default()
+        };
+        let snapped = snap(&square(23.0, 47.0, 10.0), &[], &settings);
+        assert!(snapped.point == p(25.0, 45.0));
+        // both edges land on grid lines on both axes
+        assert!(snapped.guides.len() == 4);
+        assert!(snapped.guides.iter().all(|g| g.kind == GuideKind::Grid));
+    }
+
+    #[test]
+    fn test_out_of_tolerance() {
+        let settings = SnapSettings {
+            grid_size: Some(100.0),
+            tolerance: 5.0,
+            ..SnapSettings::default()
+        };
+        let snapped = snap(
+            &square(130.0, 260.0, 10.0),
+            &[square(0.0, 0.0, 10.0)],
+            &settings,
+        );
+        assert!(snapped.point == p(130.0, 260.0));
+        assert!(snapped.guides.is_empty());
+    }
+
+    #[test]
+    fn test_unbounded_shape_is_not_moved() {
+        let line = Affine2D::new(
+            3.0,
+            4.0,
+            Line2D {
+                angle: Angle::degrees(30.0),
+            }
+            .into(),
+        );
+        let settings = SnapSettings {
+            grid_size: Some(10.0),
+            ..SnapSettings::default()
+        };
+        let snapped = snap(&line, &[square(0.0, 0.0, 10.0)], &settings);
+        assert!(snapped.point == p(3.0, 4.0));
+        assert!(snapped.guides.is_empty());
+    }
+}
+
+mod alignment {
+    use super::*;
+    #[test]
+    fn test_snap_edge_to_edge() {
+        let snapped = snap(
+            &square(30.0, 12.0, 10.0),
+            &[square(0.0, 0.0, 20.0)],
+            &no_grid(),
+        );
+        // the bottom edge moves onto the top edge of the other square
+        assert!(snapped.point == p(30.0, 15.0));
+        assert!(snapped.guides.len() == 1);
+        let guide = &snapped.guides[0];
+        assert!(guide.kind == GuideKind::Edge);
+        assert!(guide.segment.get_start() == p(-10.0, 10.0));
+        assert!(guide.segment.get_end() == p(35.0, 10.0));
+    }
+
+    #[test]
+    fn test_snap_centers() {
+        let snapped = snap(
+            &square(3.0, 40.0, 10.0),
+            &[square(0.0, 0.0, 30.0)],
+            &no_grid(),
+        );
+        assert!(snapped.point == p(0.0, 40.0));
+        assert!(snapped.guides.len() == 1);
+        assert!(snapped.guides[0].kind == GuideKind::Center);
+    }
+}
+
+mod equal_spacing {
+    use super::*;
+    #[test]
+    fn test_repeat_gap_of_row() {
+        let others = [square(0.0, 0.0, 10.0), square(20.0, 0.0, 10.0)];
+        let snapped = snap(&square(42.0, 0.0, 10.0), &others, &no_grid());
+        assert!(snapped.point == p(40.0, 0.0));
+        let spacing: Vec<_> = snapped
+            .guides
+            .iter()
+            .filter(|g| g.kind == GuideKind::Spacing)
+            .map(|g| (g.segment.get_start(), g.segment.get_end()))
+            .collect();
+        assert!(spacing.len() == 2);
+        assert!(spacing.contains(&(p(5.0, 0.0), p(15.0, 0.0))));
+        assert!(spacing.contains(&(p(25.0, 0.0), p(35.0, 0.0))));
+    }
+
+    #[test]
+    fn test_center_between_neighbours() {
+        let others = [square(0.0, 0.0, 10.0), square(40.0, 0.0, 10.0)];
+        let snapped = snap(&square(21.0, 0.0, 10.0), &others, &no_grid());
+        assert!(snapped.point == p(20.0, 0.0));
+        let spacing = snapped
+            .guides
+            .iter()
+            .filter(|g| g.kind == GuideKind::Spacing)
+            .count();
+        assert!(spacing == 2);
+    }
+
+    #[test]
+    fn test_gap_across_a_shape_is_not_offered() {
+        let others = [
+            square(0.0, 0.0, 10.0),
+            square(20.0, 0.0, 10.0),
+            square(40.0, 0.0, 10.0),
+        ];
+        // 30 past the last square repeats the gap from the first to the last one
+        let snapped = snap(&square(81.0, 0.0, 10.0), &others, &no_grid());
+        assert!(snapped.point == p(81.0, 0.0));
+        assert!(snapped.guides.iter().all(|g| g.kind != GuideKind::Spacing));
+    }
+
+    #[test]
+    fn test_column_spacing() {
+        let others = [square(0.0, 0.0, 10.0), square(0.0, 25.0, 10.0)];
+        let snapped = snap(&square(0.0, 47.0, 10.0), &others, &no_grid());
+        assert!(snapped.point == p(0.0, 50.0));
+        assert!(snapped.guides.iter().any(|g| g.kind == GuideKind::Spacing
+            && g.segment.get_start() == p(0.0, 30.0)
+            && g.segment.get_end() == p(0.0, 45.0)));
+    }
+}
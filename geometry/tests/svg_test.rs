@@ -0,0 +1,230 @@
+use geometry::{
+    angle::Angle,
+    bounding::BoundingBox,
+    point::Point2D,
+    shapes::{
+        affine::Affine2D, arc::EllipticalArc, circle::Circle, line::Line2D, polygon::Polygon,
+        rectangle::Rectangle, rounded_rectangle::RoundedRectangle,
+    },
+    svg::{PathDataError, PathElement, parse_path, path_data, to_svg_document, to_svg_element},
+};
+
+fn assert_close(a: f32, b: f32) {
+    assert!((a - b).abs() < 0.001, "{a} != {b}");
+}
+
+fn p(x: f32, y: f32) -> Point2D {
+    Point2D { x, y }
+}
+
+mod path_import {
+    use super::*;
+    #[test]
+    fn test_closed_straight_path_becomes_polygon() {
+        let elements = parse_path("M0,0 H10 V10 h-10 z").unwrap();
+        assert_eq!(elements.len(), 1);
+        let PathElement::Polygon(polygon) = &elements[0] else {
+            panic!("expected polygon, got {elements:?}");
+        };
+        assert_eq!(polygon.vertices.len(), 4);
+        assert!(polygon.vertices[2] == p(10.0, -10.0));
+    }
+
+    #[test]
+    fn test_implicit_lines_and_compact_numbers() {
+        let elements = parse_path("m1-1 2.5.5e1 1e0,0").unwrap();
+        let PathElement::Polyline(polyline) = &elements[0] else {
+            panic!("expected polyline, got {elements:?}");
+        };
+        assert_eq!(polyline.points.len(), 3);
+        assert!(polyline.points[0] == p(1.0, 1.0));
+        assert!(polyline.points[1] == p(3.5, -4.0));
+        assert!(polyline.points[2] == p(4.5, -4.0));
+    }
+
+    #[test]
+    fn test_curves_split_runs() {
+        let elements = parse_path("M0 0 L1 0 C2 0 3 1 3 2 Q3 4 1 4 T-1 4 Z").unwrap();
+        assert_eq!(elements.len(), 5);
+        assert!(matches!(elements[0], PathElement::Polyline(_)));
+        let PathElement::Bezier(cubic) = &elements[1] else {
+            panic!("expected curve, got {elements:?}");
+        };
+        assert!(cubic.start == p(1.0, 0.0) && cubic.end == p(3.0, -2.0));
+        let PathElement::Bezier(smooth) = &elements[3] else {
+            panic!("expected curve, got {elements:?}");
+        };
+        // the control point of `Q` reflected through (1, 4) is (-1, 4), y flipped
+        assert!(smooth.control1 == p(1.0 - 4.0 / 3.0, -4.0));
+        let PathElement::Polyline(closing) = &elements[4] else {
+            panic!("expected closing line, got {elements:?}");
+        };
+        assert!(closing.points[1] == p(0.0, 0.0));
+    }
+
+    #[test]
+    fn test_arcs() {
+        let elements = parse_path("M10 0 A10 10 0 0 1 -10 0 a5 5 0 1 0 0 0").unwrap();
+        assert_eq!(elements.len(), 1);
+        let PathElement::Arc(arc) = &elements[0] else {
+            panic!("expected arc, got {elements:?}");
+        };
+        assert!(arc.center == p(0.0, 0.0));
+        // a positive sweep in SVG turns clockwise once the y axis points up
+        assert_close(arc.sweep.as_degrees(), -180.0);
+        assert!(arc.point_at(0.5) == p(0.0, -10.0));
+        // radii too small to reach the end point are scaled up
+        let elements = parse_path("M0 0 A1 1 0 0 0 4 0").unwrap();
+        let PathElement::Arc(arc) = &elements[0] else {
+            panic!("expected arc, got {elements:?}");
+        };
+        assert_close(arc.radius_x, 2.0);
+        assert!(arc.point_at(0.5) == p(2.0, -2.0));
+    }
+
+    #[test]
+    fn test_errors() {
+        assert_eq!(
+            parse_path("10 10").unwrap_err(),
+            PathDataError { offset: 0 }
+        );
+        assert!(parse_path("M0 0 L1").is_err());
+        assert_eq!(
+            parse_path("M0 0 X1 1").unwrap_err(),
+            PathDataError { offset: 5 }
+        );
+        assert!(parse_path("M0 0 A1 1 0 2 0 1 1").is_err());
+        assert!(parse_path("").unwrap().is_empty());
+    }
+}
+
+mod path_export {
+    use super::*;
+    #[test]
+    fn test_round_trip() {
+        let data = "M0 0 L1 0 C2 0 3 1 3 2 A2 2 0 1 1 1 2 Z M5 5 L6 5 L6 6 Z";
+        let elements = parse_path(data).unwrap();
+        let written = path_data(&elements);
+        let read = parse_path(&written).unwrap();
+        assert_eq!(read.len(), elements.len() + 1);
+        assert!(matches!(read[2], PathElement::Arc(_)));
+        assert!(matches!(read[3], PathElement::Arc(_)));
+        let PathElement::Polygon(square) = read.last().unwrap() else {
+            panic!("expected polygon, got {read:?}");
+        };
+        assert!(square.vertices[1] == p(6.0, -5.0));
+    }
+
+    #[test]
+    fn test_arc_to_beziers() {
+        let arc = EllipticalArc::from_end_points(
+            &p(1.0, 0.0),
+            &p(-1.0, 0.0),
+            1.0,
+            1.0,
+            &Angle::degrees(0.0),
+            false,
+            true,
+        )
+        .unwrap();
+        let curves = arc.to_beziers();
+        assert_eq!(curves.len(), 2);
+        assert!(curves[0].start == p(1.0, 0.0));
+        assert!(curves[1].end == p(-1.0, 0.0));
+        let middle = curves[0].point_at(0.5);
+        assert_close(middle.x.hypot(middle.y), 1.0);
+    }
+}
+
+mod element_export {
+    use super::*;
+    #[test]
+    fn test_shape_elements() {
+        let rect = Affine2D::new(
+            1.0,
+            1.0,
+            Rectangle {
+                width: 4.0,
+                height: 2.0,
+            }
+            .into(),
+        );
+        assert_eq!(
+            to_svg_element(&rect).unwrap(),
+            r#"<rect x="-1" y="0" width="4" height="2"/>"#
+        );
+        let card = Affine2D::new(
+            0.0,
+            0.0,
+            RoundedRectangle {
+                width: 2.0,
+                height: 2.0,
+                radius: 5.0,
+            }
+            .into(),
+        );
+        assert!(to_svg_element(&card).unwrap().contains(r#"rx="1" ry="1""#));
+        let triangle = Affine2D::new(
+            1.0,
+            0.0,
+            Polygon::new(vec![p(0.0, 0.0), p(2.0, 0.0), p(0.0, 2.0)]).into(),
+        );
+        assert_eq!(
+            to_svg_element(&triangle).unwrap(),
+            r#"<polygon points="1,0 3,0 1,2"/>"#
+        );
+        let line = Affine2D::new(
+            0.0,
+            0.0,
+            Line2D {
+                angle: Angle::degrees(0.0),
+            }
+            .into(),
+        );
+        assert!(to_svg_element(&line).is_none());
+    }
+
+    #[test]
+    fn test_document() {
+        let shapes = [
+            Affine2D::new(0.0, 0.0, Circle { radius: 1.0 }.into()),
+            Affine2D::new(
+                0.0,
+                5.0,
+                Line2D {
+                    angle: Angle::degrees(90.0),
+                }
+                .into(),
+            ),
+        ];
+        let document = to_svg_document(&shapes, 1.0);
+        assert!(document.starts_with("<svg"));
+        assert!(document.contains(r#"viewBox="-2 -6 4 8""#));
+        assert!(document.contains(r#"<circle cx="0" cy="0" r="1"/>"#));
+        assert_eq!(document.matches("<line").count(), 1);
+        assert!(document.ends_with("</svg>"));
+    }
+
+    #[test]
+    fn test_imported_icon_keeps_orientation() {
+        // a house with its roof on top, the y axis of path data points down
+        let elements = parse_path("M0 10 L10 10 L10 0 L0 0 Z M0 0 L10 0 L5 -5 Z").unwrap();
+        let shapes: Vec<Affine2D> = elements
+            .iter()
+            .map(|element| {
+                let PathElement::Polygon(polygon) = element else {
+                    panic!("expected polygon, got {element:?}");
+                };
+                Affine2D::new(0.0, 0.0, polygon.clone().into())
+            })
+            .collect();
+        let roof = shapes[1].bounding_box().unwrap();
+        assert!(roof.get_bottom() >= shapes[0].bounding_box().unwrap().get_top());
+        let document = to_svg_document(&shapes, 0.0);
+        // the image covers the same area as the source path
+        assert!(document.contains(r#"viewBox="0 -5 10 15""#));
+        // and `scale(1 -1)` puts the apex back at (5, -5)
+        assert!(document.contains(r#"<polygon points="0,0 10,0 5,5"/>"#));
+        assert!(document.contains(r#"transform="scale(1 -1)""#));
+    }
+}
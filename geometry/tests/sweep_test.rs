@@ -0,0 +1,114 @@
+use geometry::{
+    point::Point2D,
+    shapes::{affine::TypedAffine2D, polyline::Polyline, segment::Segment2D},
+    sweep::{polyline_crossings, segment_crossings},
+};
+
+fn segment(x1: f32, y1: f32, x2: f32, y2: f32) -> TypedAffine2D<Segment2D> {
+    TypedAffine2D::<Segment2D>::from_points(&Point2D { x: x1, y: y1 }, &Point2D { x: x2, y: y2 })
+}
+
+fn sorted_pairs(segments: &[TypedAffine2D<Segment2D>]) -> Vec<(usize, usize)> {
+    let mut pairs: Vec<_> = segment_crossings(segments)
+        .iter()
+        .map(|c| c.segments)
+        .collect();
+    pairs.sort();
+    pairs
+}
+
+/// Deterministic pseudo random numbers in [0, 1)
+fn random_values(count: usize) -> Vec<f32> {
+    let mut state: u64 = 0x2545_f491_4f6c_dd1d;
+    (0..count)
+        .map(|_| {
+            state = state
+                .wrapping_mul(6364136223846793005)
+                .wrapping_add(1442695040888963407);
+            (state >> 40) as f32 / (1u64 << 24) as f32
+        })
+        .collect()
+}
+
+mod segments {
+    use super::*;
+
+    #[test]
+    fn test_simple_cross() {
+        let crossings = segment_crossings(&[
+            segment(0.0, 0.0, 2.0, 2.0),
+            segment(0.0, 2.0, 2.0, 0.0),
+            segment(3.0, 0.0, 4.0, 0.0),
+        ]);
+        assert!(crossings.len() == 1);
+        assert!(crossings[0].segments == (0, 1));
+        assert!(crossings[0].point == Point2D { x: 1.0, y: 1.0 });
+    }
+
+    #[test]
+    fn test_degenerate_cases() {
+        let segments = [
+            // vertical through the crossing of the two diagonals
+            segment(1.0, -1.0, 1.0, 3.0),
+            segment(0.0, 0.0, 2.0, 2.0),
+            segment(0.0, 2.0, 2.0, 0.0),
+            // shares an end point with the first diagonal
+            segment(2.0, 2.0, 4.0, 2.0),
+            // overlaps the previous one
+            segment(3.0, 2.0, 5.0, 2.0),
+            // point on the vertical
+            segment(1.0, 3.0, 1.0, 3.0),
+        ];
+        assert!(sorted_pairs(&segments) == vec![(0, 1), (0, 2), (0, 5), (1, 2), (1, 3), (3, 4)]);
+    }
+
+    #[test]
+    fn test_matches_pairwise_intersections() {
+        let values = random_values(4 * 300);
+        let segments: Vec<_> = values
+            .chunks(4)
+            .map(|v| {
+                let (x, y) = (v[0] * 100.0, v[1] * 100.0);
+                segment(x, y, x + (v[2] - 0.5) * 30.0, y + (v[3] - 0.5) * 30.0)
+            })
+            .collect();
+        let mut expected = vec![];
+        for i in 0..segments.len() {
+            for j in i + 1..segments.len() {
+                if segments[i].segment_intersection(&segments[j]).is_some() {
+                    expected.push((i, j));
+                }
+            }
+        }
+        assert!(!expected.is_empty());
+        assert!(sorted_pairs(&segments) == expected);
+    }
+}
+
+mod polylines {
+    use super::*;
+
+    #[test]
+    fn test_links_sharing_a_node() {
+        let hub = Point2D { x: 0.0, y: 0.0 };
+        let polylines = vec![
+            Polyline::new(vec![hub, Point2D { x: 4.0, y: 0.0 }]),
+            Polyline::new(vec![
+                hub,
+                Point2D { x: 2.0, y: 2.0 },
+                Point2D { x: 2.0, y: -2.0 },
+            ]),
+            Polyline::new(vec![
+                Point2D { x: 1.0, y: -1.0 },
+                Point2D { x: 1.0, y: 1.0 },
+            ]),
+        ];
+        let mut crossings: Vec<_> = polyline_crossings(&polylines)
+            .iter()
+            .map(|c| c.polylines)
+            .collect();
+        crossings.sort();
+        // 0 and 1 meet at the hub, which is not a crossing, but 1 comes back through 0
+        assert!(crossings == vec![(0, 1), (0, 2), (1, 2)]);
+    }
+}
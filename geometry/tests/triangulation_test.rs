@@ -0,0 +1,113 @@
+use geometry::{
+    clipping::difference,
+    measure::Measure,
+    point::Point2D,
+    shapes::{
+        affine::EmbedInAffine2D,
+        polygon::{Polygon, PolygonWithHoles},
+    },
+    triangulation::{TriangleMesh, triangulate},
+};
+
+fn assert_close(a: f32, b: f32) {
+    assert!((a - b).abs() < 0.001, "{a} != {b}");
+}
+
+fn square(x: f32, y: f32, size: f32) -> Polygon {
+    Polygon::new(vec![
+        Point2D { x, y },
+        Point2D { x: x + size, y },
+        Point2D {
+            x: x + size,
+            y: y + size,
+        },
+        Point2D { x, y: y + size },
+    ])
+}
+
+fn mesh_area(mesh: &TriangleMesh) -> f32 {
+    (0..mesh.triangle_count())
+        .map(|i| {
+            let [a, b, c] = mesh.get_triangle(i);
+            let area = (b - a).cross(&(c - a)) / 2.0;
+            assert!(area > 0.0, "triangle {i} is not counter-clockwise");
+            area
+        })
+        .sum()
+}
+
+mod simple_polygons {
+    use super::*;
+    #[test]
+    fn test_square() {
+        let mesh = triangulate(&PolygonWithHoles::new(square(0.0, 0.0, 2.0), vec![]));
+        assert_eq!(mesh.triangle_count(), 2);
+        assert_close(mesh_area(&mesh), 4.0);
+        assert_eq!(mesh.get_vertex_buffer().len(), 8);
+    }
+
+    #[test]
+    fn test_clockwise_input() {
+        let mesh = triangulate(&PolygonWithHoles {
+            outer: square(0.0, 0.0, 2.0).reversed(),
+            holes: vec![],
+        });
+        assert_close(mesh_area(&mesh), 4.0);
+    }
+
+    #[test]
+    fn test_concave_polygon() {
+        let u = Polygon::new(vec![
+            Point2D { x: 0.0, y: 0.0 },
+            Point2D { x: 3.0, y: 0.0 },
+            Point2D { x: 3.0, y: 3.0 },
+            Point2D { x: 2.0, y: 3.0 },
+            Point2D { x: 2.0, y: 1.0 },
+            Point2D { x: 1.0, y: 1.0 },
+            Point2D { x: 1.0, y: 3.0 },
+            Point2D { x: 0.0, y: 3.0 },
+        ]);
+        let affine = u.embed_affine(&Point2D { x: 10.0, y: 10.0 });
+        let mesh = TriangleMesh::from(&affine);
+        assert_eq!(mesh.triangle_count(), 6);
+        assert_close(mesh_area(&mesh), affine.area());
+    }
+}
+
+mod polygons_with_holes {
+    use super::*;
+    #[test]
+    fn test_single_hole() {
+        let polygon = PolygonWithHoles::new(square(0.0, 0.0, 4.0), vec![square(1.0, 1.0, 2.0)]);
+        let mesh = triangulate(&polygon);
+        assert_eq!(mesh.triangle_count(), 8);
+        assert_close(mesh_area(&mesh), 12.0);
+    }
+
+    #[test]
+    fn test_multiple_holes() {
+        let polygon = PolygonWithHoles::new(
+            square(0.0, 0.0, 10.0),
+            vec![
+                square(1.0, 1.0, 2.0),
+                square(5.0, 1.0, 2.0),
+                square(3.0, 6.0, 3.0),
+            ],
+        );
+        let mesh = triangulate(&polygon);
+        assert_close(mesh_area(&mesh), 100.0 - 4.0 - 4.0 - 9.0);
+    }
+
+    #[test]
+    fn test_boolean_result() {
+        let frames = difference(
+            &[PolygonWithHoles::new(square(0.0, 0.0, 6.0), vec![])],
+            &[PolygonWithHoles::new(square(2.0, 2.0, 2.0), vec![])],
+        );
+        let mut mesh = TriangleMesh::default();
+        for frame in &frames {
+            mesh.extend(triangulate(frame));
+        }
+        assert_close(mesh_area(&mesh), 32.0);
+    }
+}
@@ -0,0 +1,202 @@
+use geometry::{
+    angle::Angle,
+    point::Point2D,
+    shapes::{
+        Shape2D, affine::Affine2D, circle::Circle, rectangle::Rectangle,
+        rounded_rectangle::RoundedRectangle,
+    },
+    vector::Vector2D,
+    viewport::Viewport,
+};
+
+fn assert_close(a: f32, b: f32) {
+    assert!((a - b).abs() < 0.001, "{a} != {b}");
+}
+
+fn p(x: f32, y: f32) -> Point2D {
+    Point2D { x, y }
+}
+
+fn apply(matrix: &[[f32; 3]; 3], point: &Point2D) -> Point2D {
+    Point2D {
+        x: matrix[0][0] * point.x + matrix[1][0] * point.y + matrix[2][0],
+        y: matrix[0][1] * point.x + matrix[1][1] * point.y + matrix[2][1],
+    }
+}
+
+/// 800 x 600 viewport looking at (10, 20), zoomed in twice and turned by 30 degrees
+fn turned_viewport() -> Viewport {
+    let mut viewport = Viewport::new(800.0, 600.0);
+    viewport.center = p(10.0, 20.0);
+    viewport.zoom = 2.0;
+    viewport.rotation = Angle::degrees(30.0);
+    viewport
+}
+
+mod conversions {
+    use super::*;
+    #[test]
+    fn test_default_viewport() {
+        let viewport = Viewport::new(800.0, 600.0);
+        assert!(viewport.world_to_screen(&p(0.0, 0.0)) == p(400.0, 300.0));
+        // world y grows upwards, screen y downwards
+        assert!(viewport.world_to_screen(&p(10.0, 10.0)) == p(410.0, 290.0));
+        assert!(viewport.screen_to_world(&p(0.0, 0.0)) == p(-400.0, 300.0));
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let viewport = turned_viewport();
+        for point in [p(0.0, 0.0), p(-35.0, 12.5), p(1000.0, -3.0)] {
+            let screen = viewport.world_to_screen(&point);
+            assert!(viewport.screen_to_world(&screen) == point);
+        }
+        let v = Vector2D { x: 3.0, y: -4.0 };
+        let screen_v = viewport.world_to_screen_vector(&v);
+        assert_close(screen_v.length(), 10.0);
+        let back = viewport.screen_to_world_vector(&screen_v);
+        assert_close(back.x, 3.0);
+        assert_close(back.y, -4.0);
+    }
+
+    #[test]
+    fn test_rotation_is_counter_clockwise_on_screen() {
+        let mut viewport = Viewport::new(800.0, 600.0);
+        viewport.rotation = Angle::degrees(90.0);
+        // world x axis points up on screen
+        assert!(viewport.world_to_screen(&p(10.0, 0.0)) == p(400.0, 290.0));
+    }
+
+    #[test]
+    fn test_matrices_match_conversions() {
+        let viewport = turned_viewport();
+        let screen = viewport.world_to_screen_matrix();
+        let clip = viewport.world_to_clip_matrix();
+        for point in [p(0.0, 0.0), p(-35.0, 12.5), p(100.0, 40.0)] {
+            let expected = viewport.world_to_screen(&point);
+            assert!(apply(&screen, &point) == expected);
+            let in_clip = apply(&clip, &point);
+            assert_close(in_clip.x, expected.x / 400.0 - 1.0);
+            assert_close(in_clip.y, 1.0 - expected.y / 300.0);
+        }
+    }
+}
+
+mod navigation {
+    use super::*;
+    #[test]
+    fn test_pan_follows_pointer() {
+        let mut viewport = turned_viewport();
+        let grabbed = viewport.screen_to_world(&p(100.0, 100.0));
+        viewport.pan(&Vector2D { x: 50.0, y: -20.0 });
+        assert!(viewport.world_to_screen(&grabbed) == p(150.0, 80.0));
+    }
+
+    #[test]
+    fn test_zoom_keeps_pivot() {
+        let mut viewport = turned_viewport();
+        let pivot = p(620.0, 150.0);
+        let under_pivot = viewport.screen_to_world(&pivot);
+        viewport.zoom_at(1.5, &pivot);
+        assert_close(viewport.zoom, 3.0);
+        assert!(viewport.screen_to_world(&pivot) == under_pivot);
+        viewport.zoom_at(0.1, &pivot);
+        assert!(viewport.screen_to_world(&pivot) == under_pivot);
+    }
+
+    #[test]
+    fn test_rotate_keeps_pivot() {
+        let mut viewport = turned_viewport();
+        let pivot = p(20.0, 580.0);
+        let under_pivot = viewport.screen_to_world(&pivot);
+        viewport.rotate_at(&Angle::degrees(-45.0), &pivot);
+        assert_close(viewport.rotation.as_degrees(), 345.0);
+        assert!(viewport.screen_to_world(&pivot) == under_pivot);
+    }
+
+    #[test]
+    fn test_visible_rect() {
+        let mut viewport = Viewport::new(800.0, 600.0);
+        viewport.center = p(10.0, 20.0);
+        viewport.zoom = 2.0;
+        let visible = viewport.get_visible_rect();
+        assert_close(visible.get_left(), -190.0);
+        assert_close(visible.get_right(), 210.0);
+        assert_close(visible.get_bottom(), -130.0);
+        assert_close(visible.get_top(), 170.0);
+        viewport.rotation = Angle::degrees(90.0);
+        let visible = viewport.get_visible_rect();
+        assert_close(visible.shape.width, 300.0);
+        assert_close(visible.shape.height, 400.0);
+        let area = viewport.get_visible_area();
+        for corner in area.get_vertices() {
+            let screen = viewport.world_to_screen(&corner);
+            assert!(screen.x.abs() < 0.01 || (screen.x - 800.0).abs() < 0.01);
+            assert!(screen.y.abs() < 0.01 || (screen.y - 600.0).abs() < 0.01);
+        }
+    }
+}
+
+mod shapes {
+    use super::*;
+    #[test]
+    fn test_shapes_to_screen() {
+        let mut viewport = Viewport::new(800.0, 600.0);
+        viewport.zoom = 2.0;
+        let circle = viewport.world_to_screen_shape(&Affine2D::new(
+            10.0,
+            0.0,
+            Circle { radius: 5.0 }.into(),
+        ));
+        assert!(circle.point == p(420.0, 300.0));
+        assert!(matches!(circle.shape, Shape2D::Circle { circle } if circle.radius == 10.0));
+        viewport.rotation = Angle::degrees(90.0);
+        let rect = Affine2D::new(
+            0.0,
+            0.0,
+            Rectangle {
+                width: 4.0,
+                height: 2.0,
+            }
+            .into(),
+        );
+        let Shape2D::Rectangle { rect: on_screen } = viewport.world_to_screen_shape(&rect).shape
+        else {
+            panic!("quarter turns keep rectangles");
+        };
+        assert_close(on_screen.width, 4.0);
+        assert_close(on_screen.height, 8.0);
+        let back = viewport.screen_to_world_shape(&viewport.world_to_screen_shape(&rect));
+        let Shape2D::Rectangle { rect: back } = back.shape else {
+            panic!("quarter turns keep rectangles");
+        };
+        assert_close(back.width, 4.0);
+        assert_close(back.height, 2.0);
+    }
+
+    #[test]
+    fn test_turned_rectangles_become_polygons() {
+        let viewport = turned_viewport();
+        let card = Affine2D::new(
+            15.0,
+            25.0,
+            RoundedRectangle {
+                width: 4.0,
+                height: 2.0,
+                radius: 0.5,
+            }
+            .into(),
+        );
+        let on_screen = viewport.world_to_screen_shape(&card);
+        assert!(on_screen.point == viewport.world_to_screen(&p(15.0, 25.0)));
+        let Shape2D::Polygon { polygon } = on_screen.shape else {
+            panic!("turned rounded rectangles are polygons");
+        };
+        assert!(polygon.vertices.len() == 36);
+        for v in &polygon.vertices {
+            let world = viewport.screen_to_world(&(on_screen.point + (*v - Point2D::zero())));
+            let from_center = world - p(15.0, 25.0);
+            assert!(from_center.x.abs() <= 2.0 + 0.001 || from_center.y.abs() <= 1.0 + 0.001);
+        }
+    }
+}